@@ -48,14 +48,13 @@ fn create_mod_project_file(
         },
         version: "0.1.0".to_string(),
         description: "".to_string(),
+        keywords: vec![],
         authors: vec![ModProjectAuthor::Name("<Your Name>".to_string())],
+        repository: None,
+        homepage: None,
     };
 
-    let mod_project_file_content = toml::to_string(&mod_project)?;
-    std::fs::write(
-        mod_project_dir_path.as_ref().join("modproject.toml"),
-        mod_project_file_content,
-    )?;
+    mod_project.to_file(&mod_project_dir_path.as_ref().join("modproject.toml"))?;
 
     Ok(())
 }
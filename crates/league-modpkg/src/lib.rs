@@ -1,46 +1,45 @@
 use chunk::ModpkgChunk;
-use license::ModpkgLicense;
+pub use license::ModpkgLicense;
+pub use metadata::*;
 use std::collections::HashMap;
 
 mod chunk;
 mod error;
 mod license;
+mod metadata;
 mod read;
 
 #[derive(Debug, PartialEq)]
 pub struct Modpkg {
-    name: String,
-    display_name: String,
-    description: Option<String>,
-    version: String,
-    distributor: Option<String>,
-    authors: Vec<ModpkgAuthor>,
-    license: ModpkgLicense,
+    metadata: ModpkgMetadata,
 
     chunks: HashMap<u64, ModpkgChunk>,
 }
 
 impl Modpkg {
+    pub fn metadata(&self) -> &ModpkgMetadata {
+        &self.metadata
+    }
     pub fn name(&self) -> &str {
-        &self.name
+        self.metadata.name()
     }
     pub fn display_name(&self) -> &str {
-        &self.display_name
+        self.metadata.display_name()
     }
     pub fn description(&self) -> Option<&str> {
-        self.description.as_deref()
+        self.metadata.description()
     }
     pub fn version(&self) -> &str {
-        &self.version
+        self.metadata.version()
     }
     pub fn distributor(&self) -> Option<&str> {
-        self.distributor.as_deref()
+        self.metadata.distributor()
     }
     pub fn authors(&self) -> &[ModpkgAuthor] {
-        &self.authors
+        self.metadata.authors()
     }
     pub fn license(&self) -> &ModpkgLicense {
-        &self.license
+        self.metadata.license()
     }
     pub fn chunks(&self) -> &HashMap<u64, ModpkgChunk> {
         &self.chunks
@@ -53,10 +52,27 @@ pub struct ModpkgAuthor {
     role: Option<String>,
 }
 
+impl ModpkgAuthor {
+    pub fn new(name: impl Into<String>, role: Option<String>) -> Self {
+        Self {
+            name: name.into(),
+            role,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn role(&self) -> Option<&str> {
+        self.role.as_deref()
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub enum ModpkgCompression {
     None = 0,
     Zstd = 1,
+    Lz4 = 2,
 }
 
 impl TryFrom<u8> for ModpkgCompression {
@@ -66,7 +82,38 @@ impl TryFrom<u8> for ModpkgCompression {
         Ok(match value {
             0 => ModpkgCompression::None,
             1 => ModpkgCompression::Zstd,
+            2 => ModpkgCompression::Lz4,
             _ => return Err("Invalid modpkg compression value"),
         })
     }
 }
+
+impl std::fmt::Display for ModpkgCompression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ModpkgCompression::None => "none",
+            ModpkgCompression::Zstd => "zstd",
+            ModpkgCompression::Lz4 => "lz4",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compression_from_u8() {
+        assert_eq!(ModpkgCompression::try_from(0), Ok(ModpkgCompression::None));
+        assert_eq!(ModpkgCompression::try_from(1), Ok(ModpkgCompression::Zstd));
+        assert_eq!(ModpkgCompression::try_from(2), Ok(ModpkgCompression::Lz4));
+        assert!(ModpkgCompression::try_from(3).is_err());
+    }
+
+    #[test]
+    fn compression_display() {
+        assert_eq!(ModpkgCompression::None.to_string(), "none");
+        assert_eq!(ModpkgCompression::Zstd.to_string(), "zstd");
+        assert_eq!(ModpkgCompression::Lz4.to_string(), "lz4");
+    }
+}
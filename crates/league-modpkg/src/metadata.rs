@@ -0,0 +1,200 @@
+use mod_project::{ModProject, ModProjectAuthor};
+
+use crate::{ModpkgAuthor, ModpkgLicense};
+
+#[derive(Debug, PartialEq)]
+pub struct ModpkgMetadata {
+    pub(crate) name: String,
+    pub(crate) display_name: String,
+    pub(crate) description: Option<String>,
+    pub(crate) version: String,
+    pub(crate) distributor: Option<String>,
+    pub(crate) authors: Vec<ModpkgAuthor>,
+    pub(crate) license: ModpkgLicense,
+}
+
+impl ModpkgMetadata {
+    pub fn builder() -> ModpkgMetadataBuilder {
+        ModpkgMetadataBuilder::default()
+    }
+
+    pub fn from_mod_project(project: &ModProject) -> Self {
+        ModpkgMetadataBuilder::from_project(project).build()
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn display_name(&self) -> &str {
+        &self.display_name
+    }
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+    pub fn distributor(&self) -> Option<&str> {
+        self.distributor.as_deref()
+    }
+    pub fn authors(&self) -> &[ModpkgAuthor] {
+        &self.authors
+    }
+    pub fn license(&self) -> &ModpkgLicense {
+        &self.license
+    }
+}
+
+#[derive(Debug)]
+pub struct ModpkgMetadataBuilder {
+    name: String,
+    display_name: String,
+    description: Option<String>,
+    version: String,
+    distributor: Option<String>,
+    authors: Vec<ModpkgAuthor>,
+    license: ModpkgLicense,
+}
+
+impl Default for ModpkgMetadataBuilder {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            display_name: String::new(),
+            description: None,
+            version: String::new(),
+            distributor: None,
+            authors: vec![],
+            license: ModpkgLicense::None,
+        }
+    }
+}
+
+impl ModpkgMetadataBuilder {
+    /// Creates a builder pre-filled with the project's name, display name, version, description
+    /// and authors. An empty description is treated as missing.
+    pub fn from_project(project: &ModProject) -> Self {
+        let builder = Self::default()
+            .with_name(&project.name)
+            .with_display_name(&project.display_name)
+            .with_version(&project.version)
+            .with_authors(project.authors.iter().map(|author| match author {
+                ModProjectAuthor::Name(name) => ModpkgAuthor::new(name, None),
+                ModProjectAuthor::Role { name, role } => {
+                    ModpkgAuthor::new(name, Some(role.clone()))
+                }
+            }));
+
+        match project.description.is_empty() {
+            true => builder,
+            false => builder.with_description(&project.description),
+        }
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+    pub fn with_display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.display_name = display_name.into();
+        self
+    }
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+    pub fn with_version(mut self, version: impl Into<String>) -> Self {
+        self.version = version.into();
+        self
+    }
+    pub fn with_distributor(mut self, distributor: impl Into<String>) -> Self {
+        self.distributor = Some(distributor.into());
+        self
+    }
+    pub fn with_authors<I: IntoIterator<Item = ModpkgAuthor>>(mut self, authors: I) -> Self {
+        self.authors.extend(authors);
+        self
+    }
+    pub fn with_author(mut self, author: ModpkgAuthor) -> Self {
+        self.authors.push(author);
+        self
+    }
+    pub fn with_license(mut self, license: ModpkgLicense) -> Self {
+        self.license = license;
+        self
+    }
+
+    pub fn build(self) -> ModpkgMetadata {
+        ModpkgMetadata {
+            name: self.name,
+            display_name: self.display_name,
+            description: self.description,
+            version: self.version,
+            distributor: self.distributor,
+            authors: self.authors,
+            license: self.license,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_metadata() {
+        let metadata = ModpkgMetadata::builder()
+            .with_name("test")
+            .with_display_name("Test 123")
+            .with_version("0.1.0")
+            .with_description("test")
+            .with_author(ModpkgAuthor::new("test", None))
+            .with_author(ModpkgAuthor::new("test 2", Some("developer".to_string())))
+            .with_license(ModpkgLicense::Spdx {
+                spdx_id: "MIT".to_string(),
+            })
+            .build();
+
+        assert_eq!(metadata.name(), "test");
+        assert_eq!(metadata.display_name(), "Test 123");
+        assert_eq!(metadata.version(), "0.1.0");
+        assert_eq!(metadata.description(), Some("test"));
+        assert_eq!(metadata.distributor(), None);
+        assert_eq!(metadata.authors().len(), 2);
+        assert_eq!(metadata.authors()[1].role(), Some("developer"));
+    }
+
+    #[test]
+    fn from_mod_project() {
+        let project = ModProject {
+            name: "test".to_string(),
+            display_name: "Test 123".to_string(),
+            version: "0.1.0".to_string(),
+            description: "".to_string(),
+            keywords: vec![],
+            authors: vec![
+                ModProjectAuthor::Name("test".to_string()),
+                ModProjectAuthor::Role {
+                    name: "test 2".to_string(),
+                    role: "developer".to_string(),
+                },
+            ],
+            repository: None,
+            homepage: None,
+        };
+
+        let metadata = ModpkgMetadata::from_mod_project(&project);
+
+        assert_eq!(metadata.name(), "test");
+        assert_eq!(metadata.display_name(), "Test 123");
+        assert_eq!(metadata.version(), "0.1.0");
+        assert_eq!(metadata.description(), None);
+        assert_eq!(
+            metadata.authors(),
+            [
+                ModpkgAuthor::new("test", None),
+                ModpkgAuthor::new("test 2", Some("developer".to_string())),
+            ]
+        );
+    }
+}
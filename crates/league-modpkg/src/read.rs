@@ -5,7 +5,7 @@ use std::{
     io::{BufReader, Read},
 };
 
-use crate::{error::ModpkgError, Modpkg, ModpkgAuthor, ModpkgChunk, ModpkgLicense};
+use crate::{error::ModpkgError, Modpkg, ModpkgAuthor, ModpkgChunk, ModpkgLicense, ModpkgMetadata};
 
 impl Modpkg {
     pub const MAGIC: u64 = u64::from_le_bytes(*b"_modpkg_");
@@ -31,19 +31,21 @@ impl Modpkg {
         let license = ModpkgLicense::read(reader)?;
         let chunks = Self::read_chunks(reader)?;
         Ok(Self {
-            name,
-            display_name,
-            description: match description.len() {
-                0 => None,
-                _ => Some(description),
-            },
-            version,
-            distributor: match distributor.len() {
-                0 => None,
-                _ => Some(distributor),
+            metadata: ModpkgMetadata {
+                name,
+                display_name,
+                description: match description.len() {
+                    0 => None,
+                    _ => Some(description),
+                },
+                version,
+                distributor: match distributor.len() {
+                    0 => None,
+                    _ => Some(distributor),
+                },
+                authors,
+                license,
             },
-            authors,
-            license,
             chunks,
         })
     }
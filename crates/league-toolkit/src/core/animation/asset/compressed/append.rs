@@ -0,0 +1,123 @@
+use crate::core::animation::asset::compressed::frame::{Frame, TransformType};
+use crate::core::animation::asset::compressed::quantized::{compress_vec3, decompress_vec3};
+use crate::core::animation::{AppendError, Compressed};
+
+impl Compressed {
+    /// Concatenates two animations end-to-end.
+    ///
+    /// Both animations must have the same frame rate and joints. Frame times are rescaled to the
+    /// combined duration, translations and scales are requantized to the combined ranges, and the
+    /// jump caches are rebuilt.
+    pub fn append(first: &Compressed, second: &Compressed) -> Result<Compressed, AppendError> {
+        if first.fps != second.fps {
+            return Err(AppendError::FpsMismatch(first.fps, second.fps));
+        }
+        if first.joints != second.joints {
+            return Err(AppendError::JointMismatch);
+        }
+
+        let duration = first.duration + second.duration;
+        let translation_min = first.translation_min.min(second.translation_min);
+        let translation_max = first.translation_max.max(second.translation_max);
+        let scale_min = first.scale_min.min(second.scale_min);
+        let scale_max = first.scale_max.max(second.scale_max);
+
+        let mut frames = Vec::with_capacity(first.frames.len() + second.frames.len());
+        for (anim, start) in [(first, 0.0), (second, first.duration)] {
+            frames.extend(anim.frames.iter().map(|frame| {
+                let time = start + frame.time() as f32 / u16::MAX as f32 * anim.duration;
+                let time = match duration > 0.0 {
+                    true => (time / duration * u16::MAX as f32).round() as u16,
+                    false => 0,
+                };
+                let value = match frame.transform_type() {
+                    TransformType::Rotation => frame.value(),
+                    TransformType::Translation => compress_vec3(
+                        decompress_vec3(frame.value(), anim.translation_min, anim.translation_max),
+                        translation_min,
+                        translation_max,
+                    ),
+                    TransformType::Scale => compress_vec3(
+                        decompress_vec3(frame.value(), anim.scale_min, anim.scale_max),
+                        scale_min,
+                        scale_max,
+                    ),
+                };
+                Frame::new(time, frame.joint_id(), frame.transform_type(), value)
+            }));
+        }
+
+        let mut result = Compressed {
+            flags: first.flags.clone(),
+            duration,
+            fps: first.fps,
+            rotation_error_metric: first.rotation_error_metric.clone(),
+            translation_error_metric: first.translation_error_metric.clone(),
+            scale_error_metric: first.scale_error_metric.clone(),
+            translation_min,
+            translation_max,
+            scale_min,
+            scale_max,
+            jump_cache_count: 0,
+            frames,
+            jump_caches: vec![],
+            joints: first.joints.clone(),
+        };
+        result.rebuild_jump_cache();
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Vec3;
+
+    fn anim(duration: f32, translation_max: Vec3, frames: Vec<Frame>) -> Compressed {
+        Compressed {
+            translation_max,
+            ..Compressed::from_frames(duration, 30.0, vec![0xdeadbeef], frames)
+        }
+    }
+
+    #[test]
+    fn append() {
+        let translation = |time, value| Frame::new(time, 0, TransformType::Translation, value);
+        let first = anim(
+            1.0,
+            Vec3::ONE,
+            vec![translation(0, [0; 3]), translation(u16::MAX, [u16::MAX; 3])],
+        );
+        let second = anim(
+            3.0,
+            Vec3::splat(2.0),
+            vec![translation(0, [0; 3]), translation(u16::MAX, [u16::MAX; 3])],
+        );
+
+        let result = Compressed::append(&first, &second).unwrap();
+
+        assert_eq!(result.duration(), 4.0);
+        assert_eq!(result.translation_max, Vec3::splat(2.0));
+        assert_eq!(
+            result.frames.iter().map(Frame::time).collect::<Vec<_>>(),
+            [0, 16384, 16384, u16::MAX]
+        );
+        assert_eq!(
+            result.frames.iter().map(Frame::value).collect::<Vec<_>>(),
+            [[0; 3], [32768; 3], [0; 3], [u16::MAX; 3]]
+        );
+        assert_eq!(result.jump_cache_count, 120);
+    }
+
+    #[test]
+    fn append_mismatched_joints() {
+        let first = anim(1.0, Vec3::ONE, vec![]);
+        let mut second = anim(1.0, Vec3::ONE, vec![]);
+        second.joints = vec![0xcafe];
+
+        assert!(matches!(
+            Compressed::append(&first, &second),
+            Err(AppendError::JointMismatch)
+        ));
+    }
+}
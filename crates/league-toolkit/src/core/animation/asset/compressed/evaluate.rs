@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+
+use glam::{Quat, Vec3};
+
+use crate::core::animation::asset::compressed::frame::TransformType;
+use crate::core::animation::asset::compressed::quantized::{decompress_quat, decompress_vec3};
+use crate::core::animation::Compressed;
+
+/// The keys of a single joint transform component, sorted by time
+struct Track<T> {
+    keys: Vec<(u16, T)>,
+    /// Index of the last key at or before the previously sampled time
+    cursor: usize,
+}
+
+impl<T: Copy> Track<T> {
+    fn new() -> Self {
+        Self {
+            keys: vec![],
+            cursor: 0,
+        }
+    }
+
+    fn sample(&mut self, time: u16, default: T, interpolate: impl Fn(T, T, f32) -> T) -> T {
+        if self.keys.is_empty() {
+            return default;
+        }
+
+        // only search from scratch when going back in time
+        if self.keys[self.cursor].0 > time {
+            self.cursor = self
+                .keys
+                .partition_point(|&(t, _)| t <= time)
+                .saturating_sub(1);
+        }
+        while self.cursor + 1 < self.keys.len() && self.keys[self.cursor + 1].0 <= time {
+            self.cursor += 1;
+        }
+
+        let (t0, v0) = self.keys[self.cursor];
+        match self.keys.get(self.cursor + 1) {
+            Some(&(t1, v1)) if time > t0 => {
+                interpolate(v0, v1, (time - t0) as f32 / (t1 - t0) as f32)
+            }
+            _ => v0,
+        }
+    }
+}
+
+/// Samples a [`Compressed`] animation repeatedly, reusing the position in each track between
+/// calls so that stepping forward in time is cheap.
+///
+/// Keys are interpolated linearly (spherically for rotations).
+pub struct CompressedEvaluator {
+    duration: f32,
+    joints: Vec<u32>,
+    rotations: Vec<Track<Quat>>,
+    translations: Vec<Track<Vec3>>,
+    scales: Vec<Track<Vec3>>,
+    pose: Vec<(Quat, Vec3, Vec3)>,
+}
+
+impl CompressedEvaluator {
+    pub fn new(animation: &Compressed) -> Self {
+        let joint_count = animation.joints.len();
+        let mut rotations = (0..joint_count).map(|_| Track::new()).collect::<Vec<_>>();
+        let mut translations = (0..joint_count).map(|_| Track::new()).collect::<Vec<_>>();
+        let mut scales = (0..joint_count).map(|_| Track::new()).collect::<Vec<_>>();
+
+        for frame in &animation.frames {
+            let joint_id = frame.joint_id() as usize;
+            if joint_id >= joint_count {
+                continue;
+            }
+            let (time, value) = (frame.time(), frame.value());
+            match frame.transform_type() {
+                TransformType::Rotation => rotations[joint_id]
+                    .keys
+                    .push((time, decompress_quat(value))),
+                TransformType::Translation => translations[joint_id].keys.push((
+                    time,
+                    decompress_vec3(value, animation.translation_min, animation.translation_max),
+                )),
+                TransformType::Scale => scales[joint_id].keys.push((
+                    time,
+                    decompress_vec3(value, animation.scale_min, animation.scale_max),
+                )),
+            }
+        }
+        for track in &mut rotations {
+            track.keys.sort_by_key(|&(time, _)| time);
+        }
+        for track in translations.iter_mut().chain(scales.iter_mut()) {
+            track.keys.sort_by_key(|&(time, _)| time);
+        }
+
+        let mut evaluator = Self {
+            duration: animation.duration,
+            joints: animation.joints.clone(),
+            rotations,
+            translations,
+            scales,
+            pose: vec![(Quat::IDENTITY, Vec3::ZERO, Vec3::ONE); joint_count],
+        };
+        evaluator.step(0.0);
+        evaluator
+    }
+
+    /// Samples every joint at `time` (in seconds), clamped to the animation's duration
+    pub fn step(&mut self, time: f32) {
+        let time = match self.duration > 0.0 {
+            true => ((time / self.duration).clamp(0.0, 1.0) * u16::MAX as f32).round() as u16,
+            false => 0,
+        };
+
+        for (i, pose) in self.pose.iter_mut().enumerate() {
+            *pose = (
+                self.rotations[i].sample(time, Quat::IDENTITY, Quat::slerp),
+                self.translations[i].sample(time, Vec3::ZERO, Vec3::lerp),
+                self.scales[i].sample(time, Vec3::ONE, Vec3::lerp),
+            );
+        }
+    }
+
+    /// The `(rotation, translation, scale)` of every joint (by name hash) at the last sampled time
+    pub fn current_pose(&self) -> HashMap<u32, (Quat, Vec3, Vec3)> {
+        self.joints
+            .iter()
+            .copied()
+            .zip(self.pose.iter().copied())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::animation::asset::compressed::frame::Frame;
+    use crate::core::animation::asset::compressed::quantized::compress_quat;
+
+    #[test]
+    fn step() {
+        let rotation = Quat::from_rotation_y(1.0);
+        let mut animation = Compressed::from_frames(
+            2.0,
+            30.0,
+            vec![0xdeadbeef],
+            vec![
+                Frame::new(0, 0, TransformType::Translation, [0; 3]),
+                Frame::new(0, 0, TransformType::Rotation, compress_quat(rotation)),
+                Frame::new(u16::MAX, 0, TransformType::Translation, [u16::MAX; 3]),
+            ],
+        );
+        animation.translation_max = Vec3::splat(4.0);
+
+        let mut evaluator = CompressedEvaluator::new(&animation);
+        for (time, translation) in [(1.0, 2.0), (2.0, 4.0), (0.5, 1.0), (10.0, 4.0)] {
+            evaluator.step(time);
+            let (r, t, s) = evaluator.current_pose()[&0xdeadbeef];
+            assert!(t.abs_diff_eq(Vec3::splat(translation), 1e-3), "{time}: {t}");
+            assert!(r.abs_diff_eq(rotation, 1e-3));
+            assert_eq!(s, Vec3::ONE);
+        }
+    }
+}
@@ -9,16 +9,38 @@ pub struct Frame {
 }
 
 impl Frame {
+    pub fn new(time: u16, joint_id: u16, transform_type: TransformType, value: [u16; 3]) -> Self {
+        Self {
+            time,
+            joint_id: (joint_id & 0x3fff) | (u16::from(u8::from(transform_type)) << 14),
+            value,
+        }
+    }
+
+    pub fn time(&self) -> u16 {
+        self.time
+    }
     pub fn joint_id(&self) -> u16 {
         self.joint_id & 0x3fff
     }
+    pub fn value(&self) -> [u16; 3] {
+        self.value
+    }
+    /// Frames are validated when read (and [`Frame::new`] takes a [`TransformType`]), so the
+    /// transform type bits are always valid.
     pub fn transform_type(&self) -> TransformType {
-        TransformType::try_from_primitive((self.joint_id >> 14) as u8)
-            .expect("invalid transform type")
+        self.try_transform_type()
+            .expect("transform type is validated on read")
+    }
+    pub(crate) fn try_transform_type(&self) -> Option<TransformType> {
+        TransformType::try_from_primitive(self.raw_transform_type()).ok()
+    }
+    pub(crate) fn raw_transform_type(&self) -> u8 {
+        (self.joint_id >> 14) as u8
     }
 }
 
-#[derive(TryFromPrimitive, IntoPrimitive)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, TryFromPrimitive, IntoPrimitive)]
 #[repr(u8)]
 pub enum TransformType {
     Rotation = 0,
@@ -0,0 +1,102 @@
+use crate::core::animation::Compressed;
+
+impl Compressed {
+    /// Recomputes the jump caches from the current frame list.
+    ///
+    /// The animation is split into `jump_cache_count` equal time intervals. For every interval,
+    /// each joint stores the indices of the 4 rotation, translation and scale frames surrounding
+    /// the start of the interval (in that order), as u16's - or u32's if there are more than
+    /// 0x10000 frames.
+    ///
+    /// The jump cache count is recomputed as well (one jump cache per frame), so a count read
+    /// from the file or left over from before an edit is never reused.
+    pub fn rebuild_jump_cache(&mut self) {
+        self.jump_cache_count = self.frame_count();
+
+        // frame indices of every (joint, transform type) track, sorted by time
+        let mut tracks = vec![[Vec::new(), Vec::new(), Vec::new()]; self.joints.len()];
+        let mut frame_ids = (0..self.frames.len()).collect::<Vec<_>>();
+        frame_ids.sort_by_key(|&i| self.frames[i].time());
+        for i in frame_ids {
+            let frame = &self.frames[i];
+            if let Some(track) = tracks.get_mut(frame.joint_id() as usize) {
+                track[u8::from(frame.transform_type()) as usize].push(i);
+            }
+        }
+
+        let use_u16_keys = self.uses_u16_frame_keys();
+        let mut jump_caches = Vec::with_capacity(
+            self.jump_cache_count * self.joints.len() * self.jump_cache_entry_size(),
+        );
+        for cache_id in 0..self.jump_cache_count {
+            let time = (cache_id * u16::MAX as usize / self.jump_cache_count) as u16;
+            for keys in tracks.iter().flatten() {
+                let cursor = keys
+                    .partition_point(|&i| self.frames[i].time() <= time)
+                    .saturating_sub(1) as isize;
+                for offset in -1..=2 {
+                    let key = match keys.len() {
+                        0 => 0,
+                        len => keys[(cursor + offset).clamp(0, len as isize - 1) as usize],
+                    };
+                    match use_u16_keys {
+                        true => jump_caches.extend_from_slice(&(key as u16).to_le_bytes()),
+                        false => jump_caches.extend_from_slice(&(key as u32).to_le_bytes()),
+                    }
+                }
+            }
+        }
+
+        self.jump_caches = jump_caches;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::frame::{Frame, TransformType};
+    use super::*;
+
+    fn u16_keys(bytes: &[u8]) -> Vec<u16> {
+        bytes
+            .chunks_exact(2)
+            .map(|b| u16::from_le_bytes([b[0], b[1]]))
+            .collect()
+    }
+
+    #[test]
+    fn rebuild_jump_cache() {
+        let frame =
+            |time, joint_id, transform_type| Frame::new(time, joint_id, transform_type, [0; 3]);
+        let mut anim = Compressed::from_frames(
+            1.0,
+            2.0,
+            vec![0xdeadbeef],
+            vec![
+                frame(0, 0, TransformType::Rotation),
+                frame(0, 0, TransformType::Translation),
+                frame(0, 0, TransformType::Scale),
+                frame(u16::MAX / 2, 0, TransformType::Rotation),
+                frame(u16::MAX, 0, TransformType::Rotation),
+                frame(u16::MAX, 0, TransformType::Translation),
+                frame(u16::MAX, 0, TransformType::Scale),
+            ],
+        );
+        // a stale count, which the rebuild should replace
+        anim.jump_cache_count = 10;
+
+        anim.rebuild_jump_cache();
+
+        assert_eq!(anim.jump_cache_count(), 2);
+        assert!(anim.uses_u16_frame_keys());
+        assert_eq!(anim.jump_cache_entry_size(), 24);
+        assert_eq!(anim.jump_caches.len(), 2 * 24);
+        assert_eq!(
+            u16_keys(&anim.jump_caches[..24]),
+            [0, 0, 3, 4, 1, 1, 5, 5, 2, 2, 6, 6]
+        );
+        assert_eq!(
+            u16_keys(&anim.jump_caches[24..]),
+            [0, 3, 4, 4, 1, 1, 5, 5, 2, 2, 6, 6]
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use glam::{vec3, Quat};
+
+use crate::core::animation::asset::compressed::frame::{Frame, TransformType};
+use crate::core::animation::asset::compressed::quantized::{compress_quat, decompress_quat};
+use crate::core::animation::Compressed;
+
+impl Compressed {
+    /// Mirrors the animation along the X axis.
+    ///
+    /// `joint_pair_map` maps left joint hashes to their right side counterparts - the animation
+    /// data of paired joints is swapped (in both directions).
+    pub fn mirror_x(&self, joint_pair_map: &HashMap<u32, u32>) -> Compressed {
+        let mirror_joint = |hash: u32| {
+            joint_pair_map
+                .get(&hash)
+                .copied()
+                .or_else(|| {
+                    joint_pair_map
+                        .iter()
+                        .find(|(_, &right)| right == hash)
+                        .map(|(&left, _)| left)
+                })
+                .unwrap_or(hash)
+        };
+
+        let frames = self
+            .frames
+            .iter()
+            .map(|frame| {
+                let value = frame.value();
+                let value = match frame.transform_type() {
+                    TransformType::Rotation => {
+                        let q = decompress_quat(value);
+                        compress_quat(Quat::from_xyzw(q.x, -q.y, -q.z, q.w))
+                    }
+                    // quantized values are relative to the range, which gets flipped below
+                    TransformType::Translation => [u16::MAX - value[0], value[1], value[2]],
+                    TransformType::Scale => value,
+                };
+                Frame::new(
+                    frame.time(),
+                    frame.joint_id(),
+                    frame.transform_type(),
+                    value,
+                )
+            })
+            .collect();
+
+        Compressed {
+            translation_min: vec3(
+                -self.translation_max.x,
+                self.translation_min.y,
+                self.translation_min.z,
+            ),
+            translation_max: vec3(
+                -self.translation_min.x,
+                self.translation_max.y,
+                self.translation_max.z,
+            ),
+            frames,
+            joints: self.joints.iter().copied().map(mirror_joint).collect(),
+            ..self.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::animation::asset::compressed::quantized::compress_vec3;
+    use glam::Vec3;
+
+    const LEFT: u32 = 0x1eff;
+    const RIGHT: u32 = 0x7157;
+    const CENTER: u32 = 0xce7e;
+
+    #[test]
+    fn mirror_x() {
+        let (translation_min, translation_max) = (vec3(-2.0, 0.0, 0.0), Vec3::splat(4.0));
+        let rotation = Quat::from_rotation_y(0.5);
+        let animation = Compressed {
+            translation_min,
+            translation_max,
+            ..Compressed::from_frames(
+                1.0,
+                30.0,
+                vec![LEFT, RIGHT, CENTER],
+                vec![
+                    Frame::new(
+                        0,
+                        0,
+                        TransformType::Translation,
+                        compress_vec3(vec3(1.0, 2.0, 3.0), translation_min, translation_max),
+                    ),
+                    Frame::new(0, 0, TransformType::Rotation, compress_quat(rotation)),
+                ],
+            )
+        };
+
+        let mirrored = animation.mirror_x(&HashMap::from([(LEFT, RIGHT)]));
+        assert_eq!(mirrored.joints(), [RIGHT, LEFT, CENTER]);
+
+        let pose = animation.evaluator().current_pose();
+        let mirrored_pose = mirrored.evaluator().current_pose();
+
+        let (r, t, _) = pose[&LEFT];
+        assert!(t.abs_diff_eq(vec3(1.0, 2.0, 3.0), 1e-3), "{t}");
+        assert!(r.dot(rotation).abs() > 0.9999);
+
+        let (r, t, _) = mirrored_pose[&RIGHT];
+        assert!(t.abs_diff_eq(vec3(-1.0, 2.0, 3.0), 1e-3), "{t}");
+        assert!(r.dot(Quat::from_rotation_y(-0.5)).abs() > 0.9999, "{r}");
+
+        let (r, t, _) = mirrored_pose[&LEFT];
+        assert_eq!((r, t), (Quat::IDENTITY, Vec3::ZERO));
+    }
+}
@@ -4,7 +4,14 @@ use crate::core::animation::asset::error_metric::ErrorMetric;
 use crate::core::animation::AnimationAsset;
 use glam::Vec3;
 
+mod append;
+mod evaluate;
 mod frame;
+mod jump_cache;
+mod mirror;
+mod quantized;
+
+pub use evaluate::CompressedEvaluator;
 mod read;
 mod write;
 
@@ -30,8 +37,136 @@ pub struct Compressed {
     joints: Vec<u32>,
 }
 
+impl Compressed {
+    pub fn duration(&self) -> f32 {
+        self.duration
+    }
+    pub fn fps(&self) -> f32 {
+        self.fps
+    }
+    /// The number of discrete frames in the animation (at least 1)
+    pub fn frame_count(&self) -> usize {
+        ((self.duration * self.fps).round() as usize).max(1)
+    }
+    pub fn joints(&self) -> &[u32] {
+        &self.joints
+    }
+
+    /// Whether jump cache entries store frame indices as u16's (as opposed to u32's), which is
+    /// the case as long as there are at most 0x10000 frames
+    pub fn uses_u16_frame_keys(&self) -> bool {
+        self.frames.len() < 0x10001
+    }
+    pub fn jump_cache_count(&self) -> usize {
+        self.jump_cache_count
+    }
+    /// The size in bytes of a single joint's entry in a jump cache
+    pub fn jump_cache_entry_size(&self) -> usize {
+        match self.uses_u16_frame_keys() {
+            true => 24,
+            false => 48,
+        }
+    }
+
+    /// Whether `joint_hash` has any rotation, translation and scale keys (in that order)
+    #[cfg(feature = "gltf")]
+    pub(crate) fn keyed_transforms(&self, joint_hash: u32) -> [bool; 3] {
+        let mut keyed = [false; 3];
+        if let Some(joint_id) = self.joints.iter().position(|&joint| joint == joint_hash) {
+            for frame in self
+                .frames
+                .iter()
+                .filter(|f| f.joint_id() as usize == joint_id)
+            {
+                keyed[u8::from(frame.transform_type()) as usize] = true;
+            }
+        }
+        keyed
+    }
+
+    pub fn evaluator(&self) -> CompressedEvaluator {
+        CompressedEvaluator::new(self)
+    }
+}
+
 impl Into<AnimationAsset> for Compressed {
     fn into(self) -> AnimationAsset {
         AnimationAsset::Compressed(self)
     }
 }
+
+#[cfg(test)]
+impl Compressed {
+    /// An animation of `joints` made up of `frames`, with translations quantized to the
+    /// `(0, 0, 0)..(1, 1, 1)` range and a constant scale of 1
+    pub(crate) fn from_frames(
+        duration: f32,
+        fps: f32,
+        joints: Vec<u32>,
+        frames: Vec<Frame>,
+    ) -> Self {
+        Self {
+            flags: AnimationFlags::empty(),
+            duration,
+            fps,
+            rotation_error_metric: ErrorMetric::default(),
+            translation_error_metric: ErrorMetric::default(),
+            scale_error_metric: ErrorMetric::default(),
+            translation_min: Vec3::ZERO,
+            translation_max: Vec3::ONE,
+            scale_min: Vec3::ONE,
+            scale_max: Vec3::ONE,
+            jump_cache_count: 0,
+            frames,
+            jump_caches: vec![],
+            joints,
+        }
+    }
+
+    /// An animation that moves `joint` from the origin to `(1, 1, 1)` over `duration` seconds
+    pub(crate) fn translation_track(joint: u32, duration: f32, fps: f32) -> Self {
+        use frame::TransformType;
+
+        Self::from_frames(
+            duration,
+            fps,
+            vec![joint],
+            vec![
+                Frame::new(0, 0, TransformType::Translation, [0; 3]),
+                Frame::new(u16::MAX, 0, TransformType::Translation, [u16::MAX; 3]),
+            ],
+        )
+    }
+
+    /// An animation that rotates `joint` from the identity to `rotation` over `duration` seconds
+    #[cfg(feature = "gltf")]
+    pub(crate) fn rotation_track(
+        joint: u32,
+        duration: f32,
+        fps: f32,
+        rotation: glam::Quat,
+    ) -> Self {
+        use frame::TransformType;
+        use quantized::compress_quat;
+
+        Self::from_frames(
+            duration,
+            fps,
+            vec![joint],
+            vec![
+                Frame::new(
+                    0,
+                    0,
+                    TransformType::Rotation,
+                    compress_quat(glam::Quat::IDENTITY),
+                ),
+                Frame::new(
+                    u16::MAX,
+                    0,
+                    TransformType::Rotation,
+                    compress_quat(rotation),
+                ),
+            ],
+        )
+    }
+}
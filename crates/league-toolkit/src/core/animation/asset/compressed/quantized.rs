@@ -0,0 +1,74 @@
+use glam::{Quat, Vec3};
+use std::f64::consts::SQRT_2;
+
+pub fn decompress_vec3(value: [u16; 3], min: Vec3, max: Vec3) -> Vec3 {
+    min + (max - min) * Vec3::from_array(value.map(|v| v as f32)) / u16::MAX as f32
+}
+
+pub fn compress_vec3(value: Vec3, min: Vec3, max: Vec3) -> [u16; 3] {
+    let range = max - min;
+    let normalized = Vec3::select(range.cmpgt(Vec3::ZERO), (value - min) / range, Vec3::ZERO);
+    (normalized.clamp(Vec3::ZERO, Vec3::ONE) * u16::MAX as f32)
+        .round()
+        .to_array()
+        .map(|v| v as u16)
+}
+
+/// Decodes a "smallest three" quaternion - the index of the largest component is stored in the
+/// top bits, followed by the other three components as 15 bit values.
+pub fn decompress_quat(value: [u16; 3]) -> Quat {
+    let bits = value[0] as u64 | (value[1] as u64) << 16 | (value[2] as u64) << 32;
+    let max_index = (bits >> 45) & 0x3;
+    let [a, b, c] = [30, 15, 0].map(|shift| {
+        let v = (bits >> shift) & 0x7fff;
+        (v as f64 / 32767.0 * SQRT_2 - 1.0 / SQRT_2) as f32
+    });
+    let d = (1.0 - (a * a + b * b + c * c)).max(0.0).sqrt();
+
+    match max_index {
+        0 => Quat::from_xyzw(d, a, b, c),
+        1 => Quat::from_xyzw(a, d, b, c),
+        2 => Quat::from_xyzw(a, b, d, c),
+        _ => Quat::from_xyzw(a, b, c, d),
+    }
+}
+
+pub fn compress_quat(quat: Quat) -> [u16; 3] {
+    let components = quat.to_array();
+    let max_index = (0..4)
+        .max_by(|&a, &b| components[a].abs().total_cmp(&components[b].abs()))
+        .unwrap();
+    let sign = match components[max_index] < 0.0 {
+        true => -1.0,
+        false => 1.0,
+    };
+
+    let mut bits = (max_index as u64) << 45;
+    for (i, component) in (0..4).filter(|&i| i != max_index).enumerate() {
+        let v = (32767.0 / 2.0 * (SQRT_2 * components[component] as f64 * sign + 1.0)).round();
+        bits |= (v.clamp(0.0, 32767.0) as u64) << (15 * (2 - i));
+    }
+
+    [bits as u16, (bits >> 16) as u16, (bits >> 32) as u16]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quat_round_trip() {
+        for quat in [
+            Quat::IDENTITY,
+            Quat::from_rotation_x(1.0),
+            Quat::from_rotation_y(-2.5),
+            Quat::from_euler(glam::EulerRot::XYZ, 0.3, -1.2, 2.0),
+        ] {
+            let result = decompress_quat(compress_quat(quat));
+            assert!(
+                result.abs_diff_eq(quat, 1e-4) || result.abs_diff_eq(-quat, 1e-4),
+                "{quat} != {result}"
+            );
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use crate::core::animation::asset::compressed::frame::Frame;
 use crate::core::animation::asset::error_metric::ErrorMetric;
-use crate::core::animation::AssetParseError::{InvalidField, InvalidFileVersion, MissingData};
+use crate::core::animation::AssetParseError::{
+    InvalidFileVersion, InvalidFlags, InvalidJumpCacheCount, InvalidTransformType, MissingData,
+};
 use crate::core::animation::{asset, Compressed};
 use bitflags::bitflags;
 use std::io::{Read, Seek, SeekFrom};
@@ -31,12 +33,14 @@ impl Compressed {
         let resource_size = reader.read_u32::<LE>()?;
         let format_token = reader.read_u32::<LE>()?;
         let flags = reader.read_u32::<LE>()?;
-        let flags = AnimationFlags::from_bits(flags)
-            .ok_or_else(|| InvalidField("flags", flags.to_string()))?;
+        let flags = AnimationFlags::from_bits(flags).ok_or(InvalidFlags(flags))?;
 
         let joint_count = reader.read_u32::<LE>()?;
         let frame_count = reader.read_u32::<LE>()?;
         let jump_cache_count = reader.read_i32::<LE>()?;
+        if jump_cache_count < 0 {
+            return Err(InvalidJumpCacheCount(jump_cache_count));
+        }
 
         let duration = reader.read_f32::<LE>()?;
         let fps = reader.read_f32::<LE>()?;
@@ -66,7 +70,7 @@ impl Compressed {
 
         // Read joint hashes
         reader.seek(SeekFrom::Start(joint_name_hashes_off as u64 + 12))?;
-        let mut joints = Vec::with_capacity(joint_count as usize);
+        let mut joints = Vec::with_capacity((joint_count as usize).min(0x10000));
         // TODO (alan): consider direct memory reinterp
         for _ in 0..joint_count {
             joints.push(reader.read_u32::<LE>()?);
@@ -74,8 +78,8 @@ impl Compressed {
 
         // Read frames
         reader.seek(SeekFrom::Start(frames_off as u64 + 12))?;
-        let mut frames = Vec::with_capacity(frame_count as usize);
-        for _ in 0..frame_count {
+        let mut frames = Vec::with_capacity((frame_count as usize).min(0x10000));
+        for i in 0..frame_count as usize {
             let mut frame = [0; size_of::<Frame>()];
             reader.read_exact(&mut frame)?;
             let p = frame.as_ptr() as usize;
@@ -84,6 +88,12 @@ impl Compressed {
                 panic!("bad alignment!");
             }
             let frame = unsafe { std::mem::transmute::<_, Frame>(frame) };
+            if frame.try_transform_type().is_none() {
+                return Err(InvalidTransformType {
+                    frame: i,
+                    transform_type: frame.raw_transform_type(),
+                });
+            }
             frames.push(frame);
         }
 
@@ -93,9 +103,18 @@ impl Compressed {
             true => 24,
             false => 48,
         };
-        let mut jump_caches =
-            Vec::with_capacity(jump_cache_count as usize * jump_frame_size * joint_count as usize);
-        reader.read_exact(&mut jump_caches)?;
+        // the size comes straight from the header, so read what's actually there instead of
+        // allocating it up front
+        let jump_caches_size = (jump_cache_count as u64)
+            .checked_mul(jump_frame_size * joint_count as u64)
+            .ok_or(InvalidJumpCacheCount(jump_cache_count))?;
+        let mut jump_caches = vec![];
+        reader
+            .take(jump_caches_size)
+            .read_to_end(&mut jump_caches)?;
+        if jump_caches.len() as u64 != jump_caches_size {
+            return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+        }
 
         Ok(Self {
             flags,
@@ -108,10 +127,79 @@ impl Compressed {
             translation_max,
             scale_min,
             scale_max,
-            jump_cache_count: 0,
+            jump_cache_count: jump_cache_count as usize,
             frames,
             jump_caches,
             joints,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::animation::AssetParseError;
+    use byteorder::{WriteBytesExt as _, LE};
+    use std::io::Cursor;
+
+    /// A version 3 animation of a single joint, with no jump caches
+    fn animation_bytes(frames: &[[u16; 5]]) -> Vec<u8> {
+        let mut data = b"r3d2canm".to_vec();
+        for value in [3, 0, 0, 0, 1, frames.len() as u32, 0] {
+            data.write_u32::<LE>(value).unwrap();
+        }
+        // duration, fps, error metrics, translation min/max, scale min/max
+        for value in [1.0, 30.0].into_iter().chain([0.0; 6]).chain([0.0; 12]) {
+            data.write_f32::<LE>(value).unwrap();
+        }
+        // offsets are relative to byte 12; the joint hash and frames follow the header
+        let frames_off = 132 - 12;
+        let jump_caches_off = frames_off + 10 * frames.len() as i32;
+        for offset in [frames_off, jump_caches_off, 128 - 12] {
+            data.write_i32::<LE>(offset).unwrap();
+        }
+        data.write_u32::<LE>(0xdeadbeef).unwrap();
+        for frame in frames {
+            for value in frame {
+                data.write_u16::<LE>(*value).unwrap();
+            }
+        }
+        data
+    }
+
+    #[test]
+    fn read() {
+        let data = animation_bytes(&[[0, 1 << 14, 0, 0, 0], [u16::MAX, 2 << 14, 0, 0, 0]]);
+        let animation = Compressed::from_reader(&mut Cursor::new(data)).unwrap();
+
+        assert_eq!(animation.joints(), [0xdeadbeef]);
+        assert_eq!(animation.frames.len(), 2);
+    }
+
+    #[test]
+    fn truncated_jump_caches() {
+        let mut data = animation_bytes(&[[0, 1 << 14, 0, 0, 0]]);
+        // jump cache count
+        data[32..36].copy_from_slice(&i32::MAX.to_le_bytes());
+        let err = Compressed::from_reader(&mut Cursor::new(data)).unwrap_err();
+
+        assert!(matches!(
+            err,
+            AssetParseError::ReaderError(e) if e.kind() == std::io::ErrorKind::UnexpectedEof
+        ));
+    }
+
+    #[test]
+    fn invalid_transform_type() {
+        let data = animation_bytes(&[[0, 0, 0, 0, 0], [0, 3 << 14, 0, 0, 0]]);
+        let err = Compressed::from_reader(&mut Cursor::new(data)).unwrap_err();
+
+        assert!(matches!(
+            err,
+            AssetParseError::InvalidTransformType {
+                frame: 1,
+                transform_type: 3
+            }
+        ));
+    }
+}
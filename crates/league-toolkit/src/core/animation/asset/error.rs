@@ -4,8 +4,12 @@ pub enum AssetParseError {
     UnknownAssetType,
     #[error("Invalid file version '{0}'")]
     InvalidFileVersion(u32),
-    #[error("Invalid '{0}' - got '{1}'")]
-    InvalidField(&'static str, String),
+    #[error("Invalid animation flags '{0:#x}'")]
+    InvalidFlags(u32),
+    #[error("Invalid jump cache count '{0}'")]
+    InvalidJumpCacheCount(i32),
+    #[error("Invalid transform type '{transform_type}' in frame {frame}")]
+    InvalidTransformType { frame: usize, transform_type: u8 },
 
     #[error("Animation does not contain {0} data!")]
     MissingData(&'static str),
@@ -17,3 +21,15 @@ pub enum AssetParseError {
 }
 
 pub type Result<T> = core::result::Result<T, AssetParseError>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum AppendError {
+    #[error("Animations have different frame rates - '{0}' and '{1}'")]
+    FpsMismatch(f32, f32),
+    #[error("Animations do not animate the same joints")]
+    JointMismatch,
+    #[error("Cannot append animations of different asset types")]
+    AssetTypeMismatch,
+    #[error("Appending {0} animations is not supported")]
+    Unsupported(&'static str),
+}
@@ -12,6 +12,7 @@ mod error_metric;
 pub use error::*;
 
 use error::AssetParseError::UnknownAssetType;
+use std::collections::HashMap;
 use std::io;
 use std::io::{Read, Seek, SeekFrom};
 
@@ -40,6 +41,60 @@ impl AnimationAsset {
         }
     }
 
+    /// The frame rate of the animation, or `None` for uncompressed animations (not supported yet)
+    pub fn fps(&self) -> Option<f32> {
+        match self {
+            Self::Compressed(asset) => Some(asset.fps()),
+            Self::Uncompressed(_) => None,
+        }
+    }
+
+    /// The number of frames in the animation, or `None` for uncompressed animations (not
+    /// supported yet)
+    pub fn frame_count(&self) -> Option<usize> {
+        match self {
+            Self::Compressed(asset) => Some(asset.frame_count()),
+            Self::Uncompressed(_) => None,
+        }
+    }
+
+    /// The index of the frame being shown at `time` (in seconds), clamped to the animation's frames.
+    pub fn frame_index_at(&self, time: f32) -> Option<usize> {
+        let frame = (time * self.fps()?).floor().max(0.0) as usize;
+        Some(frame.min(self.frame_count()? - 1))
+    }
+
+    /// The time (in seconds) at which `frame` starts
+    pub fn time_at_frame(&self, frame: usize) -> Option<f32> {
+        Some(frame as f32 / self.fps()?)
+    }
+
+    /// Concatenates two animations end-to-end, so that `second` starts playing when `first` ends.
+    pub fn append(
+        first: &AnimationAsset,
+        second: &AnimationAsset,
+    ) -> std::result::Result<AnimationAsset, AppendError> {
+        match (first, second) {
+            (Self::Compressed(first), Self::Compressed(second)) => {
+                Compressed::append(first, second).map(Self::Compressed)
+            }
+            (Self::Uncompressed(_), Self::Uncompressed(_)) => {
+                Err(AppendError::Unsupported("uncompressed"))
+            }
+            _ => Err(AppendError::AssetTypeMismatch),
+        }
+    }
+
+    /// Mirrors the animation along the X axis, see [`Compressed::mirror_x`].
+    ///
+    /// Uncompressed animations carry no frame data yet, so they are returned as is.
+    pub fn mirror_x(&self, joint_pair_map: &HashMap<u32, u32>) -> AnimationAsset {
+        match self {
+            Self::Compressed(asset) => Self::Compressed(asset.mirror_x(joint_pair_map)),
+            Self::Uncompressed(asset) => Self::Uncompressed(asset.clone()),
+        }
+    }
+
     pub fn identify_from_reader<R: Read + ?Sized>(
         reader: &mut R,
     ) -> io::Result<AnimationAssetType> {
@@ -53,3 +108,40 @@ impl AnimationAsset {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_index_at() {
+        // 1.04s at 10fps - rounds to 10 frames
+        let animation: AnimationAsset = Compressed::translation_track(0, 1.04, 10.0).into();
+        assert_eq!(animation.frame_count(), Some(10));
+
+        assert_eq!(animation.frame_index_at(-1.0), Some(0));
+        assert_eq!(animation.frame_index_at(0.0), Some(0));
+        assert_eq!(animation.frame_index_at(0.55), Some(5));
+        assert_eq!(animation.frame_index_at(1.04), Some(9));
+        assert_eq!(animation.frame_index_at(5.0), Some(9));
+    }
+
+    #[test]
+    fn time_at_frame() {
+        let animation: AnimationAsset = Compressed::translation_track(0, 1.0, 10.0).into();
+
+        assert_eq!(animation.time_at_frame(0), Some(0.0));
+        assert_eq!(animation.time_at_frame(5), Some(0.5));
+        assert_eq!(animation.time_at_frame(10), Some(1.0));
+    }
+
+    #[test]
+    fn uncompressed() {
+        let animation = AnimationAsset::Uncompressed(Uncompressed {});
+
+        assert_eq!(animation.fps(), None);
+        assert_eq!(animation.frame_count(), None);
+        assert_eq!(animation.frame_index_at(0.0), None);
+        assert_eq!(animation.time_at_frame(0), None);
+    }
+}
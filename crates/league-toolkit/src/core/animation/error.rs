@@ -4,8 +4,6 @@ pub enum ParseError {
     InvalidFileSignature,
     #[error("Invalid file version '{0}'")]
     InvalidFileVersion(u32),
-    #[error("Invalid '{0}' - got '{1}'")]
-    InvalidField(&'static str, String),
     #[error("IO Error - {0}")]
     ReaderError(#[from] std::io::Error),
     #[error("UTF-8 Error - {0}")]
@@ -13,3 +11,9 @@ pub enum ParseError {
 }
 
 pub type Result<T> = core::result::Result<T, ParseError>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum RigValidationError {
+    #[error("Rig has multiple root joints {0:?}")]
+    MultipleRootJoints(Vec<i16>),
+}
@@ -0,0 +1,305 @@
+use std::collections::HashMap;
+
+use base64::Engine as _;
+use gltf_json::{
+    accessor::{ComponentType, GenericComponentType, Type},
+    animation::{Channel, Interpolation, Property, Sampler, Target},
+    buffer::View,
+    scene::UnitQuaternion,
+    validation::{Checked::Valid, USize64},
+    Accessor, Animation, Buffer, Index, Node, Root, Scene, Value,
+};
+
+use super::{AnimationAsset, RigResource};
+use crate::util::hash;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExportError {
+    #[error("Exporting {0} animations is not supported")]
+    Unsupported(&'static str),
+}
+
+/// Exports `animation` as a glTF document, with `rig` as the node hierarchy and a single
+/// animation called `name` that has a rotation, translation and scale channel per animated joint.
+///
+/// Every joint is sampled once per frame, plus once at the end of the animation. Channels are only
+/// written for the transforms a joint has keys for, so the rest keep the node's bind pose.
+/// Keyframe data is embedded as a base64 buffer.
+///
+/// Channels always use linear interpolation: compressed animations have no tangents to export
+/// as cubic splines, and the evaluator itself interpolates linearly (spherically for rotations).
+pub fn export_animation(
+    animation: &AnimationAsset,
+    rig: &RigResource,
+    name: &str,
+) -> Result<Root, ExportError> {
+    let AnimationAsset::Compressed(compressed) = animation else {
+        return Err(ExportError::Unsupported("uncompressed"));
+    };
+
+    let mut root = Root::default();
+    let nodes = push_joint_nodes(&mut root, rig);
+
+    let duration = compressed.duration();
+    let times = (0..compressed.frame_count())
+        .map(|frame| frame as f32 / compressed.fps())
+        .take_while(|&time| time < duration)
+        .chain([duration])
+        .collect::<Vec<_>>();
+    let mut evaluator = compressed.evaluator();
+    let poses = times
+        .iter()
+        .map(|&time| {
+            evaluator.step(time);
+            evaluator.current_pose()
+        })
+        .collect::<Vec<_>>();
+
+    let mut buffer = BufferWriter::new(&mut root);
+    let input = buffer.push_accessor(&mut root, &times, Type::Scalar);
+    root.accessors[input.value()].min = Some(Value::from(vec![times[0]]));
+    root.accessors[input.value()].max = Some(Value::from(vec![times[times.len() - 1]]));
+
+    let mut samplers = vec![];
+    let mut channels = vec![];
+    for joint in rig.joints() {
+        let joint_hash = hash::elf(joint.name()) as u32;
+        if !compressed.joints().contains(&joint_hash) {
+            continue;
+        }
+        let pose = |i: usize| poses[i][&joint_hash];
+        let [rotation_keyed, translation_keyed, scale_keyed] =
+            compressed.keyed_transforms(joint_hash);
+
+        let rotations = (0..times.len())
+            .flat_map(|i| pose(i).0.to_array())
+            .collect::<Vec<_>>();
+        let translations = (0..times.len())
+            .flat_map(|i| pose(i).1.to_array())
+            .collect::<Vec<_>>();
+        let scales = (0..times.len())
+            .flat_map(|i| pose(i).2.to_array())
+            .collect::<Vec<_>>();
+
+        for (keyed, property, values, type_) in [
+            (rotation_keyed, Property::Rotation, rotations, Type::Vec4),
+            (
+                translation_keyed,
+                Property::Translation,
+                translations,
+                Type::Vec3,
+            ),
+            (scale_keyed, Property::Scale, scales, Type::Vec3),
+        ] {
+            if !keyed {
+                continue;
+            }
+            let output = buffer.push_accessor(&mut root, &values, type_);
+            channels.push(Channel {
+                sampler: Index::push(
+                    &mut samplers,
+                    Sampler {
+                        extensions: None,
+                        extras: Default::default(),
+                        input,
+                        interpolation: Valid(Interpolation::Linear),
+                        output,
+                    },
+                ),
+                target: Target {
+                    extensions: None,
+                    extras: Default::default(),
+                    node: nodes[&joint.id()],
+                    path: Valid(property),
+                },
+                extensions: None,
+                extras: Default::default(),
+            });
+        }
+    }
+    buffer.finish(&mut root);
+
+    root.push(Animation {
+        extensions: None,
+        extras: Default::default(),
+        channels,
+        name: Some(name.to_string()),
+        samplers,
+    });
+    Ok(root)
+}
+
+/// Pushes a node for every joint in `rig` (in its bind pose), plus a scene containing the roots
+fn push_joint_nodes(root: &mut Root, rig: &RigResource) -> HashMap<i16, Index<Node>> {
+    let nodes = rig
+        .joints()
+        .iter()
+        .map(|joint| {
+            let node = root.push(Node {
+                name: Some(joint.name().to_string()),
+                rotation: Some(UnitQuaternion(joint.local_rotation().to_array())),
+                scale: Some(joint.local_scale().to_array()),
+                translation: Some(joint.local_translation().to_array()),
+                ..Default::default()
+            });
+            (joint.id(), node)
+        })
+        .collect::<HashMap<_, _>>();
+
+    let mut scene_nodes = vec![];
+    for joint in rig.joints() {
+        let node = nodes[&joint.id()];
+        match nodes.get(&joint.parent_id()) {
+            Some(parent) => root.nodes[parent.value()]
+                .children
+                .get_or_insert_with(Vec::new)
+                .push(node),
+            None => scene_nodes.push(node),
+        }
+    }
+
+    let scene = root.push(Scene {
+        extensions: None,
+        extras: Default::default(),
+        name: Some(rig.name().to_string()),
+        nodes: scene_nodes,
+    });
+    root.scene = Some(scene);
+
+    nodes
+}
+
+/// Packs accessor data into a single buffer, which is embedded into the document on [`Self::finish`]
+struct BufferWriter {
+    buffer: Index<Buffer>,
+    data: Vec<u8>,
+}
+
+impl BufferWriter {
+    fn new(root: &mut Root) -> Self {
+        let buffer = root.push(Buffer {
+            byte_length: USize64(0),
+            name: None,
+            uri: None,
+            extensions: None,
+            extras: Default::default(),
+        });
+        Self {
+            buffer,
+            data: vec![],
+        }
+    }
+
+    fn push_accessor(&mut self, root: &mut Root, values: &[f32], type_: Type) -> Index<Accessor> {
+        let offset = self.data.len();
+        self.data
+            .extend(values.iter().flat_map(|value| value.to_le_bytes()));
+
+        let view = root.push(View {
+            buffer: self.buffer,
+            byte_length: USize64::from(self.data.len() - offset),
+            byte_offset: Some(USize64::from(offset)),
+            byte_stride: None,
+            name: None,
+            target: None,
+            extensions: None,
+            extras: Default::default(),
+        });
+        root.push(Accessor {
+            buffer_view: Some(view),
+            byte_offset: None,
+            count: USize64::from(values.len() / type_.multiplicity()),
+            component_type: Valid(GenericComponentType(ComponentType::F32)),
+            extensions: None,
+            extras: Default::default(),
+            type_: Valid(type_),
+            min: None,
+            max: None,
+            name: None,
+            normalized: false,
+            sparse: None,
+        })
+    }
+
+    fn finish(self, root: &mut Root) {
+        let buffer = &mut root.buffers[self.buffer.value()];
+        buffer.byte_length = USize64::from(self.data.len());
+        buffer.uri = Some(format!(
+            "data:application/octet-stream;base64,{}",
+            base64::engine::general_purpose::STANDARD.encode(&self.data)
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::animation::{Compressed, Joint, Uncompressed};
+    use glam::{Mat4, Quat, Vec3};
+
+    #[test]
+    fn export_animation() {
+        let rig = RigResource::builder("rig", "rig")
+            .with_root_joint(Joint::builder("root").with_children([Joint::builder("child")]))
+            .build();
+        let animation: AnimationAsset =
+            Compressed::translation_track(hash::elf("child") as u32, 1.0, 10.0).into();
+
+        let root = super::export_animation(&animation, &rig, "idle").unwrap();
+
+        assert_eq!(root.nodes.len(), 2);
+        assert_eq!(root.scenes[0].nodes.len(), 1);
+        assert_eq!(root.animations.len(), 1);
+        assert_eq!(root.animations[0].channels.len(), 1);
+        assert_eq!(
+            root.animations[0].channels[0].target.path,
+            Valid(Property::Translation)
+        );
+        assert_eq!(root.accessors[0].count, USize64(11));
+        assert_eq!(root.accessors[0].max, Some(Value::from(vec![1.0_f32])));
+        assert!(root.buffers[0]
+            .uri
+            .as_ref()
+            .is_some_and(|uri| uri.starts_with("data:")));
+
+        let json = root.to_string().unwrap();
+        assert!(Root::from_str(&json).is_ok());
+    }
+
+    #[test]
+    fn export_rotation_only() {
+        let bind_translation = Vec3::new(0.0, 2.0, 0.0);
+        let rig = RigResource::builder("rig", "rig")
+            .with_root_joint(
+                Joint::builder("root").with_children([Joint::builder("child")
+                    .with_local_transform(Mat4::from_translation(bind_translation))]),
+            )
+            .build();
+        let rotation = Quat::from_rotation_z(1.0);
+        let animation: AnimationAsset =
+            Compressed::rotation_track(hash::elf("child") as u32, 1.0, 10.0, rotation).into();
+
+        let root = super::export_animation(&animation, &rig, "idle").unwrap();
+
+        let channels = &root.animations[0].channels;
+        assert_eq!(channels.len(), 1);
+        assert_eq!(channels[0].target.path, Valid(Property::Rotation));
+
+        let child = &root.nodes[channels[0].target.node.value()];
+        assert_eq!(child.name.as_deref(), Some("child"));
+        assert_eq!(child.translation, Some(bind_translation.to_array()));
+    }
+
+    #[test]
+    fn export_uncompressed() {
+        let rig = RigResource::builder("rig", "rig")
+            .with_root_joint(Joint::builder("root"))
+            .build();
+        let animation = AnimationAsset::Uncompressed(Uncompressed {});
+
+        assert!(matches!(
+            super::export_animation(&animation, &rig, "idle"),
+            Err(ExportError::Unsupported("uncompressed"))
+        ));
+    }
+}
@@ -7,8 +7,13 @@ pub mod error;
 pub use error::*;
 
 pub mod asset;
+#[cfg(feature = "gltf")]
+pub mod gltf;
 pub mod rig;
 
-pub use asset::{AnimationAsset, AnimationAssetType, AssetParseError, Compressed, Uncompressed};
+pub use asset::{
+    AnimationAsset, AnimationAssetType, AppendError, AssetParseError, Compressed,
+    CompressedEvaluator, Uncompressed,
+};
 
 pub use rig::*;
@@ -1,4 +1,4 @@
-use crate::core::animation::{joint, Joint, RigResource};
+use crate::core::animation::{joint, Joint, RigResource, RigValidationError};
 use std::collections::VecDeque;
 
 pub struct Builder {
@@ -55,6 +55,25 @@ impl Builder {
             influences,
         }
     }
+
+    /// Builds the rig, then checks that there is at most one root joint (`parent_id == -1`).
+    ///
+    /// Parent ids don't need checking - the builder always assigns them from the joint tree.
+    pub fn build_validated(self) -> Result<RigResource, RigValidationError> {
+        let rig = self.build();
+
+        let root_ids = rig
+            .joints()
+            .iter()
+            .filter(|joint| joint.parent_id() == -1)
+            .map(Joint::id)
+            .collect::<Vec<_>>();
+        if root_ids.len() > 1 {
+            return Err(RigValidationError::MultipleRootJoints(root_ids));
+        }
+
+        Ok(rig)
+    }
 }
 
 #[cfg(test)]
@@ -81,4 +100,22 @@ mod tests {
 
         assert_debug_snapshot!(rig);
     }
+
+    #[test]
+    fn build_validated() {
+        let rig = RigResource::builder("my_rig", "my_rig_asset")
+            .with_root_joint(
+                Joint::builder("root").with_children([Joint::builder("a"), Joint::builder("b")]),
+            )
+            .build_validated()
+            .unwrap();
+        assert_eq!(rig.joints().len(), 3);
+
+        let err = RigResource::builder("my_rig", "my_rig_asset")
+            .with_root_joint(Joint::builder("root_1"))
+            .with_root_joint(Joint::builder("root_2"))
+            .build_validated()
+            .unwrap_err();
+        assert!(matches!(err, RigValidationError::MultipleRootJoints(ids) if ids == [0, 1]));
+    }
 }
@@ -38,4 +38,78 @@ impl RigResource {
     pub fn influences(&self) -> &[i16] {
         &self.influences
     }
+
+    pub fn joint_count(&self) -> usize {
+        self.joints.len()
+    }
+
+    /// The first joint without a parent
+    pub fn root_joint(&self) -> Option<&Joint> {
+        self.joints.iter().find(|joint| joint.parent_id() == -1)
+    }
+
+    /// The joints ordered so that every parent comes before its children.
+    ///
+    /// Joints whose parent doesn't exist are treated as roots.
+    pub fn joints_in_depth_first_order(&self) -> Vec<&Joint> {
+        let is_root = |joint: &Joint| !self.joints.iter().any(|j| j.id() == joint.parent_id());
+
+        let mut order = Vec::with_capacity(self.joints.len());
+        let mut stack = self
+            .joints
+            .iter()
+            .filter(|joint| is_root(joint))
+            .rev()
+            .collect::<Vec<_>>();
+        while let Some(joint) = stack.pop() {
+            order.push(joint);
+            stack.extend(
+                self.joints
+                    .iter()
+                    .filter(|child| child.parent_id() == joint.id())
+                    .rev(),
+            );
+        }
+        order
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rig() -> RigResource {
+        RigResource::builder("my_rig", "my_rig_asset")
+            .with_root_joint(Joint::builder("root").with_children([
+                Joint::builder("a").with_children([Joint::builder("a1")]),
+                Joint::builder("b"),
+            ]))
+            .build()
+    }
+
+    #[test]
+    fn joint_count() {
+        assert_eq!(rig().joint_count(), 4);
+    }
+
+    #[test]
+    fn root_joint() {
+        assert_eq!(rig().root_joint().map(Joint::name), Some("root"));
+        assert_eq!(
+            RigResource::builder("rig", "rig").build().root_joint(),
+            None
+        );
+    }
+
+    #[test]
+    fn joints_in_depth_first_order() {
+        assert_eq!(
+            rig()
+                .joints_in_depth_first_order()
+                .into_iter()
+                .map(Joint::name)
+                .collect::<Vec<_>>(),
+            ["root", "a", "a1", "b"]
+        );
+    }
 }
@@ -81,6 +81,17 @@ impl IndexBuffer {
     pub fn buffer(&self) -> &[u8] {
         &self.buffer
     }
+
+    /// Widens the buffer to 32 bit indices
+    pub fn into_u32(self) -> IndexBuffer {
+        match self.format {
+            IndexFormat::U16 => IndexBuffer::new(
+                IndexFormat::U32,
+                self.iter().flat_map(u32::to_le_bytes).collect(),
+            ),
+            IndexFormat::U32 => self,
+        }
+    }
 }
 
 pub struct IndexBufferIter<'a> {
@@ -100,3 +111,24 @@ impl<'a> Iterator for IndexBufferIter<'a> {
         Some(item)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn into_u32() {
+        let buffer = IndexBuffer::new(
+            IndexFormat::U16,
+            [0_u16, 1, u16::MAX].map(u16::to_le_bytes).concat(),
+        );
+
+        let widened = buffer.clone().into_u32();
+        assert_eq!(widened.format(), &IndexFormat::U32);
+        assert_eq!(widened.count(), 3);
+        assert_eq!(widened.buffer().len(), 12);
+        assert_eq!(widened.iter().collect::<Vec<_>>(), [0, 1, 65535]);
+
+        assert_eq!(widened.clone().into_u32(), widened);
+    }
+}
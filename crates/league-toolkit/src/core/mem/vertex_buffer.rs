@@ -38,10 +38,6 @@ pub struct VertexBuffer {
 
 impl VertexBuffer {
     pub fn new(usage: VertexBufferUsage, elements: Vec<VertexElement>, buffer: Vec<u8>) -> Self {
-        if buffer.is_empty() {
-            panic!("Buffer cannot be empty! FIXME (alan): don't panic here");
-        }
-
         let description = VertexBufferDescription::new(usage, elements.clone());
         let mut element_descriptors = BTreeMap::new();
         let mut off = 0;
@@ -88,7 +84,41 @@ impl VertexBuffer {
         self.count
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// The length of the raw buffer in bytes (`count * stride`)
+    pub fn byte_len(&self) -> usize {
+        self.buffer.len()
+    }
+
     pub fn buffer(&self) -> &[u8] {
         &self.buffer
     }
+
+    pub(crate) fn buffer_mut(&mut self) -> &mut [u8] {
+        &mut self.buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn byte_len() {
+        let elements = vec![VertexElement::POSITION, VertexElement::TEXCOORD_0];
+
+        let buffer = VertexBuffer::new(VertexBufferUsage::Static, elements.clone(), vec![0; 60]);
+        assert!(!buffer.is_empty());
+        assert_eq!(buffer.count(), 3);
+        assert_eq!(buffer.byte_len(), buffer.count() * buffer.stride());
+        assert_eq!(buffer.byte_len(), 60);
+
+        let empty = VertexBuffer::new(VertexBufferUsage::Static, elements, vec![]);
+        assert!(empty.is_empty());
+        assert_eq!(empty.count(), 0);
+        assert_eq!(empty.byte_len(), 0);
+    }
 }
@@ -72,7 +72,45 @@ impl VertexBufferDescription {
         &self.elements
     }
 
+    pub fn has_element(&self, name: ElementName) -> bool {
+        self.description_flags
+            .contains(get_element_flags(std::iter::once(name)))
+    }
+
+    /// The byte offset of the element within a vertex
+    pub fn element_offset(&self, name: ElementName) -> Option<usize> {
+        let index = self.elements.iter().position(|e| e.name == name)?;
+        Some(self.elements[..index].iter().map(|e| e.size()).sum())
+    }
+
     pub fn into_vertex_buffer(self, buf: Vec<u8>) -> VertexBuffer {
         VertexBuffer::new(self.usage, self.elements, buf)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn element_offset() {
+        let description = VertexBufferDescription::new(
+            VertexBufferUsage::Static,
+            vec![
+                VertexElement::POSITION,
+                VertexElement::NORMAL,
+                VertexElement::TEXCOORD_0,
+            ],
+        );
+
+        assert!(description.has_element(ElementName::Position));
+        assert!(description.has_element(ElementName::Texcoord0));
+        assert!(!description.has_element(ElementName::Tangent));
+
+        assert_eq!(description.element_offset(ElementName::Position), Some(0));
+        assert_eq!(description.element_offset(ElementName::Normal), Some(12));
+        assert_eq!(description.element_offset(ElementName::Texcoord0), Some(24));
+        assert_eq!(description.element_offset(ElementName::Tangent), None);
+        assert_eq!(description.vertex_size(), 32);
+    }
+}
@@ -1,11 +1,17 @@
+use crate::core::mem::ElementName;
+
 #[derive(Debug, thiserror::Error)]
 pub enum ParseError {
     #[error("Invalid file signature")]
     InvalidFileSignature,
     #[error("Invalid file version '{0}.{1}'")]
     InvalidFileVersion(u16, u16),
+    #[error("Unsupported static mesh version '{0}.{1}'")]
+    UnsupportedStaticMeshVersion(u16, u16),
     #[error("Invalid '{0}' - got '{1}'")]
     InvalidField(&'static str, String),
+    #[error("Index '{0}' does not fit in a 16 bit index buffer")]
+    IndexOutOfRange(u32),
     #[error("IO Error - {0}")]
     IOError(#[from] std::io::Error),
     #[error("UTF-8 Error - {0}")]
@@ -13,3 +19,43 @@ pub enum ParseError {
     #[error(transparent)]
     ReaderError(#[from] io_ext::ReaderError),
 }
+
+#[derive(Debug, thiserror::Error)]
+pub enum TangentError {
+    #[error("Vertex buffer is missing the '{0:?}' element")]
+    MissingElement(ElementName),
+    #[error("Failed to generate tangents")]
+    GenerationFailed,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RemapError {
+    #[error("Vertex buffer is missing the '{0:?}' element")]
+    MissingElement(ElementName),
+    #[error("Bone index '{0}' has no entry in the mapping")]
+    UnmappedIndex(u8),
+    #[error("Remapped bone index '{0}' does not fit in a blend index")]
+    IndexOutOfRange(u16),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MergeError {
+    #[error("No meshes to merge")]
+    NoMeshes,
+    #[error("Meshes have different vertex buffer descriptions")]
+    VertexDescriptionMismatch,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SplitError {
+    #[error(
+        "Range '{material}' has {vertex_count} vertices, which exceeds the limit of {max_vertices}"
+    )]
+    RangeTooLarge {
+        material: String,
+        vertex_count: usize,
+        max_vertices: usize,
+    },
+    #[error("Range '{0}' references vertices outside of it")]
+    IndexOutOfRange(String),
+}
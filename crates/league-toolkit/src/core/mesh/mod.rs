@@ -4,6 +4,7 @@ use error::ParseError;
 pub use r#static::*;
 
 pub mod error;
+pub mod obj;
 
 pub mod skinned;
 pub use skinned::*;
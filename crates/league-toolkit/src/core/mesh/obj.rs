@@ -0,0 +1,145 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use glam::Vec3;
+
+use super::StaticMesh;
+
+/// Writes `mesh` as a Wavefront OBJ file at `obj_path`, with its materials in an MTL file at
+/// `mtl_path`.
+///
+/// UVs are flipped vertically to match the OBJ convention. If the mesh has vertex colors, each
+/// material's `Kd` is the average color of the vertices it uses.
+pub fn export_static_mesh(mesh: &StaticMesh, obj_path: &Path, mtl_path: &Path) -> io::Result<()> {
+    let mtl_name = mtl_path
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default();
+
+    let mut obj = BufWriter::new(File::create(obj_path)?);
+    let mut mtl = BufWriter::new(File::create(mtl_path)?);
+    write_static_mesh(mesh, &mut obj, &mut mtl, &mtl_name)?;
+    obj.flush()?;
+    mtl.flush()
+}
+
+fn write_static_mesh<O: Write, M: Write>(
+    mesh: &StaticMesh,
+    obj: &mut O,
+    mtl: &mut M,
+    mtl_name: &str,
+) -> io::Result<()> {
+    let mut materials: Vec<&str> = vec![];
+    for face in mesh.faces() {
+        if !materials.contains(&face.material.as_str()) {
+            materials.push(&face.material);
+        }
+    }
+
+    for material in &materials {
+        let color = mesh
+            .vertex_colors()
+            .and_then(|colors| material_color(mesh, material, colors))
+            .unwrap_or(Vec3::ONE);
+        writeln!(mtl, "newmtl {material}")?;
+        writeln!(mtl, "Kd {} {} {}", color.x, color.y, color.z)?;
+        writeln!(mtl)?;
+    }
+
+    writeln!(obj, "mtllib {mtl_name}")?;
+    writeln!(obj, "o {}", mesh.name())?;
+    for v in mesh.vertices() {
+        writeln!(obj, "v {} {} {}", v.x, v.y, v.z)?;
+    }
+    for face in mesh.faces() {
+        for uv in [face.uvs.0, face.uvs.1, face.uvs.2] {
+            writeln!(obj, "vt {} {}", uv.x, 1.0 - uv.y)?;
+        }
+    }
+
+    let mut current_material = None;
+    for (i, face) in mesh.faces().iter().enumerate() {
+        if current_material != Some(&face.material) {
+            writeln!(obj, "usemtl {}", face.material)?;
+            current_material = Some(&face.material);
+        }
+        // OBJ indices are 1-based, and every face has its own 3 UVs
+        let (a, b, c) = face.vertex_ids;
+        let uv = i * 3 + 1;
+        writeln!(
+            obj,
+            "f {}/{} {}/{} {}/{}",
+            a as usize + 1,
+            uv,
+            b as usize + 1,
+            uv + 1,
+            c as usize + 1,
+            uv + 2
+        )?;
+    }
+
+    Ok(())
+}
+
+/// The average color of the vertices used by faces with `material`
+fn material_color(
+    mesh: &StaticMesh,
+    material: &str,
+    colors: &[league_primitives::Color],
+) -> Option<Vec3> {
+    let (sum, count) = mesh
+        .faces()
+        .iter()
+        .filter(|face| face.material == material)
+        .flat_map(|face| [face.vertex_ids.0, face.vertex_ids.1, face.vertex_ids.2])
+        .filter_map(|id| colors.get(id as usize))
+        .fold((Vec3::ZERO, 0), |(sum, count), color| {
+            (sum + Vec3::new(color.r, color.g, color.b), count + 1)
+        });
+    (count > 0).then(|| sum / count as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::mesh::StaticMeshFace;
+    use glam::vec2;
+    use league_primitives::Color;
+
+    #[test]
+    fn write_static_mesh() {
+        let face = |material: &str| StaticMeshFace {
+            material: material.to_string(),
+            vertex_ids: (0, 1, 2),
+            uvs: (vec2(0.0, 0.0), vec2(1.0, 0.0), vec2(0.0, 1.0)),
+            colors: (Color::<f32>::ONE, Color::<f32>::ONE, Color::<f32>::ONE),
+        };
+        let mesh = StaticMesh::new(
+            "mesh".to_string(),
+            vec![Vec3::ZERO, Vec3::X, Vec3::Y],
+            vec![face("a"), face("b")],
+            Some(vec![
+                Color::new(1.0, 0.0, 0.0, 1.0),
+                Color::new(0.0, 1.0, 0.0, 1.0),
+                Color::new(0.0, 0.0, 1.0, 1.0),
+            ]),
+        );
+
+        let (mut obj, mut mtl) = (vec![], vec![]);
+        super::write_static_mesh(&mesh, &mut obj, &mut mtl, "mesh.mtl").unwrap();
+        let (obj, mtl) = (
+            String::from_utf8(obj).unwrap(),
+            String::from_utf8(mtl).unwrap(),
+        );
+
+        assert!(obj.starts_with("mtllib mesh.mtl\no mesh\nv 0 0 0\n"));
+        assert!(obj.contains("vt 0 1\n"));
+        assert!(obj.contains("usemtl a\nf 1/1 2/2 3/3\nusemtl b\nf 1/4 2/5 3/6\n"));
+        assert_eq!(mtl.matches("newmtl").count(), 2);
+        let third = 1.0_f32 / 3.0;
+        assert!(mtl.contains(&format!("Kd {third} {third} {third}")));
+    }
+}
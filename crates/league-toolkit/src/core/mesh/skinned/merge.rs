@@ -0,0 +1,99 @@
+use crate::core::mem::{IndexBuffer, IndexFormat};
+use crate::core::mesh::error::MergeError;
+
+use super::{SkinnedMesh, SkinnedMeshRange};
+
+impl SkinnedMesh {
+    /// Combines several meshes into one, concatenating their vertex and index buffers and
+    /// offsetting their ranges accordingly.
+    ///
+    /// The merged index buffer is 16 bit, unless there are too many vertices to address.
+    pub fn merge(meshes: &[&SkinnedMesh]) -> Result<SkinnedMesh, MergeError> {
+        let (first, rest) = meshes.split_first().ok_or(MergeError::NoMeshes)?;
+        let description = first.vertex_buffer.description();
+        if rest
+            .iter()
+            .any(|mesh| mesh.vertex_buffer.description() != description)
+        {
+            return Err(MergeError::VertexDescriptionMismatch);
+        }
+
+        let vertex_count = meshes
+            .iter()
+            .map(|mesh| mesh.vertex_buffer.count())
+            .sum::<usize>();
+        let format = match vertex_count <= u16::MAX as usize + 1 {
+            true => IndexFormat::U16,
+            false => IndexFormat::U32,
+        };
+
+        let mut ranges = vec![];
+        let mut vertices = vec![];
+        let mut indices = vec![];
+        let (mut vertex_offset, mut index_offset) = (0, 0);
+        for mesh in meshes {
+            vertices.extend_from_slice(mesh.vertex_buffer.buffer());
+            for index in mesh.index_buffer.iter() {
+                let index = index + vertex_offset as u32;
+                match format {
+                    IndexFormat::U16 => indices.extend_from_slice(&(index as u16).to_le_bytes()),
+                    IndexFormat::U32 => indices.extend_from_slice(&index.to_le_bytes()),
+                }
+            }
+            ranges.extend(mesh.ranges.iter().map(|range| {
+                SkinnedMeshRange::new(
+                    range.material(),
+                    range.start_vertex() + vertex_offset as i32,
+                    range.vertex_count(),
+                    range.start_index() + index_offset as i32,
+                    range.index_count(),
+                )
+            }));
+
+            vertex_offset += mesh.vertex_buffer.count();
+            index_offset += mesh.index_buffer.count();
+        }
+
+        Ok(SkinnedMesh::new(
+            ranges,
+            description.clone().into_vertex_buffer(vertices),
+            IndexBuffer::new(format, indices),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Vec3;
+
+    fn triangle(material: &str, offset: Vec3) -> SkinnedMesh {
+        SkinnedMesh::basic(
+            vec![SkinnedMeshRange::new(material, 0, 3, 0, 3)],
+            &[Vec3::ZERO, Vec3::X, Vec3::Y].map(|position| (position + offset).into()),
+            &[0, 1, 2],
+        )
+    }
+
+    #[test]
+    fn merge() {
+        let a = triangle("a", Vec3::ZERO);
+        let b = triangle("b", Vec3::Z);
+
+        let merged = SkinnedMesh::merge(&[&a, &b]).unwrap();
+
+        assert_eq!(merged.vertex_buffer().count(), 6);
+        assert_eq!(
+            merged.index_buffer().iter().collect::<Vec<_>>(),
+            [0, 1, 2, 3, 4, 5]
+        );
+        assert_eq!(
+            merged.ranges(),
+            [
+                SkinnedMeshRange::new("a", 0, 3, 0, 3),
+                SkinnedMeshRange::new("b", 3, 3, 3, 3)
+            ]
+        );
+        assert_eq!(merged.range_aabb(&merged.ranges()[1]).unwrap().min, Vec3::Z);
+    }
+}
@@ -6,10 +6,13 @@ pub use range::*;
 use crate::core::mem::{ElementName, IndexBuffer, VertexBuffer, VertexBufferDescription};
 use league_primitives::{Sphere, AABB};
 
-use super::Result;
+use super::{error::RemapError, Result};
 
+mod merge;
 mod range;
 mod read;
+mod split;
+mod tangent;
 mod vertex;
 mod write;
 
@@ -64,6 +67,66 @@ impl SkinnedMesh {
     pub fn index_buffer(&self) -> &IndexBuffer {
         &self.index_buffer
     }
+
+    /// Computes the bounding box of the vertices referenced by the range's indices.
+    ///
+    /// Returns `None` if the range is empty, lies (or references vertices) outside of the mesh's
+    /// buffers, or the vertex buffer has no positions.
+    pub fn range_aabb(&self, range: &SkinnedMeshRange) -> Option<AABB> {
+        let start = usize::try_from(range.start_index()).ok()?;
+        let count = usize::try_from(range.index_count()).ok()?;
+        let end = start.checked_add(count)?;
+        if count == 0 || end > self.index_buffer.count() {
+            return None;
+        }
+        let indices = (start..end).map(|i| self.index_buffer.get(i) as usize);
+        if indices
+            .clone()
+            .any(|index| index >= self.vertex_buffer.count())
+        {
+            return None;
+        }
+
+        let positions = self.vertex_buffer.accessor::<Vec3>(ElementName::Position)?;
+        Some(AABB::from_vertex_iter(
+            indices.map(|index| positions.get(index)),
+        ))
+    }
+
+    /// Rewrites every blend index in the vertex buffer, where `mapping[old_index] = new_index`.
+    ///
+    /// The mesh is left untouched if any index cannot be remapped.
+    pub fn remap_bone_indices(&mut self, mapping: &[u16]) -> core::result::Result<(), RemapError> {
+        let offset = self
+            .vertex_buffer
+            .elements()
+            .get(&ElementName::BlendIndex)
+            .ok_or(RemapError::MissingElement(ElementName::BlendIndex))?
+            .offset() as usize;
+        let stride = self.vertex_buffer.stride();
+
+        let mut remapped = Vec::with_capacity(self.vertex_buffer.count() * 4);
+        for vertex in self.vertex_buffer.buffer().chunks_exact(stride) {
+            for &index in &vertex[offset..offset + 4] {
+                let new_index = *mapping
+                    .get(index as usize)
+                    .ok_or(RemapError::UnmappedIndex(index))?;
+                remapped.push(
+                    u8::try_from(new_index).map_err(|_| RemapError::IndexOutOfRange(new_index))?,
+                );
+            }
+        }
+
+        for (vertex, indices) in self
+            .vertex_buffer
+            .buffer_mut()
+            .chunks_exact_mut(stride)
+            .zip(remapped.chunks_exact(4))
+        {
+            vertex[offset..offset + 4].copy_from_slice(indices);
+        }
+        Ok(())
+    }
 }
 
 #[derive(
@@ -85,3 +148,151 @@ impl From<SkinnedMeshVertexType> for VertexBufferDescription {
         }
     }
 }
+
+/// A vertex of a [`vertex::BASIC`] test mesh
+#[cfg(test)]
+#[derive(Clone, Copy, Default)]
+pub(crate) struct BasicVertex {
+    pub position: Vec3,
+    pub blend_indices: [u8; 4],
+    pub normal: Vec3,
+    pub uv: glam::Vec2,
+}
+
+#[cfg(test)]
+impl From<Vec3> for BasicVertex {
+    fn from(position: Vec3) -> Self {
+        Self {
+            position,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+impl SkinnedMesh {
+    /// A mesh with the [`vertex::BASIC`] layout (and zeroed blend weights), indexed by 16 bit
+    /// `indices`
+    pub(crate) fn basic(
+        ranges: Vec<SkinnedMeshRange>,
+        vertices: &[BasicVertex],
+        indices: &[u16],
+    ) -> Self {
+        let floats = |values: &[f32]| {
+            values
+                .iter()
+                .flat_map(|v| v.to_le_bytes())
+                .collect::<Vec<_>>()
+        };
+        let mut buffer = Vec::with_capacity(vertices.len() * 52);
+        for vertex in vertices {
+            buffer.extend(floats(&vertex.position.to_array()));
+            buffer.extend(vertex.blend_indices);
+            buffer.extend(floats(&[0.0; 4]));
+            buffer.extend(floats(&vertex.normal.to_array()));
+            buffer.extend(floats(&vertex.uv.to_array()));
+        }
+        SkinnedMesh::new(
+            ranges,
+            vertex::BASIC.clone().into_vertex_buffer(buffer),
+            IndexBuffer::new(
+                crate::core::mem::IndexFormat::U16,
+                indices.iter().flat_map(|i| i.to_le_bytes()).collect(),
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A mesh with one range per 3 vertices
+    fn mesh(vertices: &[(Vec3, [u8; 4])]) -> SkinnedMesh {
+        let count = vertices.len() as i32;
+        SkinnedMesh::basic(
+            (0..count / 3)
+                .map(|i| SkinnedMeshRange::new(format!("{i}"), i * 3, 3, i * 3, 3))
+                .collect(),
+            &vertices
+                .iter()
+                .map(|&(position, blend_indices)| BasicVertex {
+                    position,
+                    blend_indices,
+                    ..Default::default()
+                })
+                .collect::<Vec<_>>(),
+            &(0..count as u16).collect::<Vec<_>>(),
+        )
+    }
+
+    #[test]
+    fn range_aabb() {
+        let mesh = mesh(&[
+            (Vec3::new(-1.0, 2.0, 0.5), [0; 4]),
+            (Vec3::new(3.0, -2.0, 0.0), [0; 4]),
+            (Vec3::new(0.0, 1.0, -4.0), [0; 4]),
+            (Vec3::splat(10.0), [0; 4]),
+            (Vec3::splat(11.0), [0; 4]),
+            (Vec3::splat(12.0), [0; 4]),
+        ]);
+
+        let aabb = mesh.range_aabb(&mesh.ranges()[0]).unwrap();
+        assert_eq!(aabb.min, Vec3::new(-1.0, -2.0, -4.0));
+        assert_eq!(aabb.max, Vec3::new(3.0, 2.0, 0.5));
+
+        let aabb = mesh.range_aabb(&mesh.ranges()[1]).unwrap();
+        assert_eq!((aabb.min, aabb.max), (Vec3::splat(10.0), Vec3::splat(12.0)));
+
+        assert_eq!(
+            mesh.range_aabb(&SkinnedMeshRange::new("empty", 0, 0, 0, 0)),
+            None
+        );
+        for (start_index, index_count) in [(-3, 3), (0, -3), (3, 6), (i32::MAX, 3)] {
+            let range = SkinnedMeshRange::new("out of range", 0, 3, start_index, index_count);
+            assert_eq!(mesh.range_aabb(&range), None, "{start_index} {index_count}");
+        }
+    }
+
+    #[test]
+    fn remap_bone_indices() {
+        let mut mesh = mesh(&[
+            (Vec3::ZERO, [0, 1, 2, 3]),
+            (Vec3::X, [3, 2, 1, 0]),
+            (Vec3::Y, [1, 1, 0, 0]),
+        ]);
+
+        mesh.remap_bone_indices(&[10, 11, 12, 13]).unwrap();
+
+        let indices = mesh
+            .vertex_buffer()
+            .buffer()
+            .chunks_exact(52)
+            .map(|vertex| <[u8; 4]>::try_from(&vertex[12..16]).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            indices,
+            [[10, 11, 12, 13], [13, 12, 11, 10], [11, 11, 10, 10]]
+        );
+    }
+
+    #[test]
+    fn remap_bone_indices_unmapped() {
+        let mut mesh = mesh(&[
+            (Vec3::ZERO, [0, 1, 2, 3]),
+            (Vec3::X, [0, 0, 0, 0]),
+            (Vec3::Y, [0, 0, 0, 0]),
+        ]);
+        let original = mesh.vertex_buffer().buffer().to_vec();
+
+        assert!(matches!(
+            mesh.remap_bone_indices(&[0, 1, 2]),
+            Err(RemapError::UnmappedIndex(3))
+        ));
+        assert!(matches!(
+            mesh.remap_bone_indices(&[0, 1, 2, 256]),
+            Err(RemapError::IndexOutOfRange(256))
+        ));
+        assert_eq!(mesh.vertex_buffer().buffer(), original);
+    }
+}
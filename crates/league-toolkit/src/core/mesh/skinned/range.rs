@@ -30,6 +30,22 @@ impl SkinnedMeshRange {
         }
     }
 
+    pub fn material(&self) -> &str {
+        &self.material
+    }
+    pub fn start_vertex(&self) -> i32 {
+        self.start_vertex
+    }
+    pub fn vertex_count(&self) -> i32 {
+        self.vertex_count
+    }
+    pub fn start_index(&self) -> i32 {
+        self.start_index
+    }
+    pub fn index_count(&self) -> i32 {
+        self.index_count
+    }
+
     pub fn from_reader<R: Read>(reader: &mut R) -> super::Result<Self> {
         Ok(Self {
             material: reader.read_padded_string::<LE, 64>()?,
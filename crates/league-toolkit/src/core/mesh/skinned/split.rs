@@ -0,0 +1,165 @@
+use crate::core::mem::{IndexBuffer, IndexFormat};
+use crate::core::mesh::error::SplitError;
+
+use super::{SkinnedMesh, SkinnedMeshRange};
+
+impl SkinnedMesh {
+    /// Splits the mesh into several meshes with at most `max_vertices` vertices each.
+    ///
+    /// Ranges are assigned greedily, in order, to the current output mesh until the next range
+    /// doesn't fit anymore. Ranges themselves are never split.
+    pub fn split_at_vertex_count(
+        &self,
+        max_vertices: usize,
+    ) -> Result<Vec<SkinnedMesh>, SplitError> {
+        let mut groups: Vec<(usize, Vec<&SkinnedMeshRange>)> = vec![];
+        for range in &self.ranges {
+            let vertex_count = range.vertex_count() as usize;
+            if vertex_count > max_vertices {
+                return Err(SplitError::RangeTooLarge {
+                    material: range.material().to_string(),
+                    vertex_count,
+                    max_vertices,
+                });
+            }
+            match groups.last_mut() {
+                Some((count, ranges)) if *count + vertex_count <= max_vertices => {
+                    *count += vertex_count;
+                    ranges.push(range);
+                }
+                _ => groups.push((vertex_count, vec![range])),
+            }
+        }
+
+        let stride = self.vertex_buffer.stride();
+        let description = self.vertex_buffer.description();
+        groups
+            .into_iter()
+            .map(|(vertex_count, ranges)| {
+                let format = match vertex_count <= u16::MAX as usize + 1 {
+                    true => IndexFormat::U16,
+                    false => IndexFormat::U32,
+                };
+
+                let mut new_ranges = vec![];
+                let mut vertices = vec![];
+                let mut indices = vec![];
+                let (mut vertex_offset, mut index_offset) = (0, 0);
+                for range in ranges {
+                    let start_vertex = range.start_vertex() as usize;
+                    let range_vertices = start_vertex..start_vertex + range.vertex_count() as usize;
+                    vertices.extend_from_slice(
+                        &self.vertex_buffer.buffer()
+                            [range_vertices.start * stride..range_vertices.end * stride],
+                    );
+
+                    let start_index = range.start_index() as usize;
+                    for i in start_index..start_index + range.index_count() as usize {
+                        let index = self.index_buffer.get(i) as usize;
+                        if !range_vertices.contains(&index) {
+                            return Err(SplitError::IndexOutOfRange(range.material().to_string()));
+                        }
+                        let index = (index - start_vertex + vertex_offset) as u32;
+                        match format {
+                            IndexFormat::U16 => {
+                                indices.extend_from_slice(&(index as u16).to_le_bytes())
+                            }
+                            IndexFormat::U32 => indices.extend_from_slice(&index.to_le_bytes()),
+                        }
+                    }
+
+                    new_ranges.push(SkinnedMeshRange::new(
+                        range.material(),
+                        vertex_offset as i32,
+                        range.vertex_count(),
+                        index_offset as i32,
+                        range.index_count(),
+                    ));
+                    vertex_offset += range.vertex_count() as usize;
+                    index_offset += range.index_count() as usize;
+                }
+
+                Ok(SkinnedMesh::new(
+                    new_ranges,
+                    description.clone().into_vertex_buffer(vertices),
+                    IndexBuffer::new(format, indices),
+                ))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Vec3;
+
+    /// Three triangles, `a`, `b` and `c`, offset along Y by 0, 1 and 2
+    fn triangles() -> SkinnedMesh {
+        SkinnedMesh::basic(
+            ["a", "b", "c"]
+                .into_iter()
+                .enumerate()
+                .map(|(i, material)| {
+                    let offset = i as i32 * 3;
+                    SkinnedMeshRange::new(material, offset, 3, offset, 3)
+                })
+                .collect(),
+            &(0..9)
+                .map(|i| Vec3::new((i % 3) as f32, (i / 3) as f32, 0.0).into())
+                .collect::<Vec<_>>(),
+            &(0..9).collect::<Vec<_>>(),
+        )
+    }
+
+    #[test]
+    fn split_at_vertex_count() {
+        let split = triangles().split_at_vertex_count(6).unwrap();
+
+        assert_eq!(split.len(), 2);
+        assert_eq!(
+            split[0].ranges(),
+            [
+                SkinnedMeshRange::new("a", 0, 3, 0, 3),
+                SkinnedMeshRange::new("b", 3, 3, 3, 3)
+            ]
+        );
+        assert_eq!(split[1].ranges(), [SkinnedMeshRange::new("c", 0, 3, 0, 3)]);
+        assert_eq!(
+            split[1].index_buffer().iter().collect::<Vec<_>>(),
+            [0, 1, 2]
+        );
+        assert_eq!(split[1].aabb().min, Vec3::new(0.0, 2.0, 0.0));
+    }
+
+    #[test]
+    fn split_range_too_large() {
+        let result = triangles().split_at_vertex_count(2);
+
+        assert!(matches!(
+            result,
+            Err(SplitError::RangeTooLarge {
+                material,
+                vertex_count: 3,
+                max_vertices: 2,
+            }) if material == "a"
+        ));
+    }
+
+    #[test]
+    fn split_index_out_of_range() {
+        let mut mesh = triangles();
+        // the second triangle references a vertex of the first one
+        mesh.index_buffer = IndexBuffer::new(
+            IndexFormat::U16,
+            [0_u16, 1, 2, 3, 4, 0, 6, 7, 8]
+                .map(u16::to_le_bytes)
+                .concat(),
+        );
+
+        assert!(matches!(
+            mesh.split_at_vertex_count(9),
+            Err(SplitError::IndexOutOfRange(material)) if material == "b"
+        ));
+    }
+}
@@ -0,0 +1,138 @@
+use glam::{Vec2, Vec3};
+
+use crate::core::mem::{ElementName, IndexBuffer, VertexBufferAccessor};
+use crate::core::mesh::error::TangentError;
+
+use super::SkinnedMesh;
+
+struct TangentGeometry<'a> {
+    index_buffer: &'a IndexBuffer,
+    positions: VertexBufferAccessor<'a, Vec3>,
+    normals: VertexBufferAccessor<'a, Vec3>,
+    uvs: VertexBufferAccessor<'a, Vec2>,
+    tangents: Vec<[f32; 4]>,
+}
+
+impl TangentGeometry<'_> {
+    fn vertex_id(&self, face: usize, vert: usize) -> usize {
+        self.index_buffer.get(face * 3 + vert) as usize
+    }
+}
+
+impl bevy_mikktspace::Geometry for TangentGeometry<'_> {
+    fn num_faces(&self) -> usize {
+        self.index_buffer.count() / 3
+    }
+
+    fn num_vertices_of_face(&self, _face: usize) -> usize {
+        3
+    }
+
+    fn position(&self, face: usize, vert: usize) -> [f32; 3] {
+        self.positions.get(self.vertex_id(face, vert)).to_array()
+    }
+
+    fn normal(&self, face: usize, vert: usize) -> [f32; 3] {
+        self.normals.get(self.vertex_id(face, vert)).to_array()
+    }
+
+    fn tex_coord(&self, face: usize, vert: usize) -> [f32; 2] {
+        self.uvs.get(self.vertex_id(face, vert)).to_array()
+    }
+
+    fn set_tangent_encoded(&mut self, tangent: [f32; 4], face: usize, vert: usize) {
+        let vertex_id = self.vertex_id(face, vert);
+        self.tangents[vertex_id] = tangent;
+    }
+}
+
+impl SkinnedMesh {
+    /// Computes a tangent for every vertex using the MikkTSpace algorithm.
+    ///
+    /// Each tangent is stored as `[x, y, z, w]`, where `w` is the handedness sign of the bitangent.
+    pub fn compute_tangents(&self) -> Result<Vec<[f32; 4]>, TangentError> {
+        let mut geometry = TangentGeometry {
+            index_buffer: &self.index_buffer,
+            positions: self
+                .vertex_buffer
+                .accessor(ElementName::Position)
+                .ok_or(TangentError::MissingElement(ElementName::Position))?,
+            normals: self
+                .vertex_buffer
+                .accessor(ElementName::Normal)
+                .ok_or(TangentError::MissingElement(ElementName::Normal))?,
+            uvs: self
+                .vertex_buffer
+                .accessor(ElementName::Texcoord0)
+                .ok_or(TangentError::MissingElement(ElementName::Texcoord0))?,
+            tangents: vec![[0.0; 4]; self.vertex_buffer.count()],
+        };
+
+        match bevy_mikktspace::generate_tangents(&mut geometry) {
+            true => Ok(geometry.tangents),
+            false => Err(TangentError::GenerationFailed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::mem::{
+        IndexFormat, VertexBufferDescription, VertexBufferUsage, VertexElement,
+    };
+    use crate::core::mesh::{skinned::BasicVertex, SkinnedMeshRange};
+    use glam::vec2;
+
+    /// A unit quad facing +Z, with U along +X and V along +Y
+    fn quad() -> SkinnedMesh {
+        SkinnedMesh::basic(
+            vec![SkinnedMeshRange::new("quad", 0, 4, 0, 6)],
+            &[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)].map(|(x, y)| BasicVertex {
+                position: Vec3::new(x, y, 0.0),
+                normal: Vec3::Z,
+                uv: vec2(x, y),
+                ..Default::default()
+            }),
+            &[0, 1, 2, 0, 2, 3],
+        )
+    }
+
+    #[test]
+    fn compute_tangents() {
+        let tangents = quad().compute_tangents().unwrap();
+
+        assert_eq!(tangents.len(), 4);
+        for [x, y, z, w] in tangents {
+            assert!(Vec3::new(x, y, z).abs_diff_eq(Vec3::X, 1e-5), "{x} {y} {z}");
+            assert_eq!(w.abs(), 1.0);
+        }
+    }
+
+    #[test]
+    fn compute_tangents_missing_uvs() {
+        let vertices = [Vec3::ZERO, Vec3::X, Vec3::Y]
+            .into_iter()
+            .flat_map(|position| [position, Vec3::Z])
+            .flat_map(|v| v.to_array())
+            .flat_map(f32::to_le_bytes)
+            .collect();
+        let mesh = SkinnedMesh::new(
+            vec![SkinnedMeshRange::new("triangle", 0, 3, 0, 3)],
+            VertexBufferDescription::new(
+                VertexBufferUsage::Static,
+                vec![VertexElement::POSITION, VertexElement::NORMAL],
+            )
+            .into_vertex_buffer(vertices),
+            IndexBuffer::new(
+                IndexFormat::U16,
+                [0_u16, 1, 2].map(u16::to_le_bytes).concat(),
+            ),
+        );
+
+        assert!(matches!(
+            mesh.compute_tangents(),
+            Err(TangentError::MissingElement(ElementName::Texcoord0))
+        ));
+    }
+}
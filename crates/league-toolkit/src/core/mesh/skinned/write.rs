@@ -1,3 +1,5 @@
+use crate::core::mem::IndexFormat;
+use crate::core::mesh::error::ParseError;
 use crate::core::mesh::skinned::{vertex, SkinnedMeshVertexType, MAGIC};
 use crate::core::mesh::SkinnedMesh;
 use byteorder::{WriteBytesExt, LE};
@@ -6,6 +8,19 @@ use std::io::Write;
 
 impl SkinnedMesh {
     pub fn to_writer<W: Write>(&self, w: &mut W) -> crate::core::mesh::Result<()> {
+        // skn files only support 16 bit indices
+        let index_buffer = match self.index_buffer.format() {
+            IndexFormat::U16 => self.index_buffer.buffer().to_vec(),
+            IndexFormat::U32 => self
+                .index_buffer
+                .iter()
+                .map(|i| u16::try_from(i).map_err(|_| ParseError::IndexOutOfRange(i)))
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
+                .flat_map(u16::to_le_bytes)
+                .collect(),
+        };
+
         w.write_u32::<LE>(MAGIC)?;
 
         w.write_u16::<LE>(4)?; // major
@@ -31,10 +46,56 @@ impl SkinnedMesh {
         w.write_aabb::<LE>(&self.aabb)?;
         w.write_sphere::<LE>(&self.bounding_sphere)?;
 
-        w.write_all(self.index_buffer.buffer())?;
+        w.write_all(&index_buffer)?;
         w.write_all(self.vertex_buffer.buffer())?;
 
         w.write_all(&[0_u8; 12])?; // end tab
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::mem::IndexBuffer;
+    use crate::core::mesh::SkinnedMeshRange;
+    use std::io::Cursor;
+
+    /// A mesh of `vertex_count` vertices at the origin, indexed by 32 bit `indices`
+    fn mesh(vertex_count: usize, indices: &[u32]) -> SkinnedMesh {
+        SkinnedMesh {
+            index_buffer: IndexBuffer::new(
+                IndexFormat::U32,
+                indices.iter().flat_map(|i| i.to_le_bytes()).collect(),
+            ),
+            ..SkinnedMesh::basic(
+                vec![SkinnedMeshRange::new(
+                    "mesh",
+                    0,
+                    vertex_count as i32,
+                    0,
+                    indices.len() as i32,
+                )],
+                &vec![Default::default(); vertex_count],
+                &[],
+            )
+        }
+    }
+
+    #[test]
+    fn narrows_u32_indices() {
+        let mut data = Cursor::new(vec![]);
+        mesh(3, &[0, 1, 2]).to_writer(&mut data).unwrap();
+        data.set_position(0);
+
+        let read = SkinnedMesh::from_reader(&mut data).unwrap();
+        assert_eq!(read.index_buffer().format(), &IndexFormat::U16);
+        assert_eq!(read.index_buffer().iter().collect::<Vec<_>>(), [0, 1, 2]);
+    }
+
+    #[test]
+    fn too_many_vertices() {
+        let result = mesh(65537, &[0, 1, 65536]).to_writer(&mut vec![]);
+        assert!(matches!(result, Err(ParseError::IndexOutOfRange(65536))));
+    }
+}
@@ -1,7 +1,7 @@
 use glam::Vec3;
 
 pub use face::*;
-use league_primitives::Color;
+use league_primitives::{Color, Sphere, AABB};
 
 mod face;
 mod read;
@@ -20,6 +20,20 @@ pub struct StaticMesh {
 // TODO (alan): figure out endianness
 
 impl StaticMesh {
+    pub fn new(
+        name: String,
+        vertices: Vec<Vec3>,
+        faces: Vec<StaticMeshFace>,
+        vertex_colors: Option<Vec<Color>>,
+    ) -> Self {
+        Self {
+            name,
+            vertices,
+            faces,
+            vertex_colors,
+        }
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
@@ -35,4 +49,127 @@ impl StaticMesh {
     pub fn vertex_colors(&self) -> Option<&Vec<Color>> {
         self.vertex_colors.as_ref()
     }
+
+    /// The axis aligned bounding box of every vertex, or an empty box at the origin if the mesh
+    /// has no vertices
+    pub fn bounding_box(&self) -> AABB {
+        match self.vertices.is_empty() {
+            true => AABB::default(),
+            false => AABB::from_vertex_iter(self.vertices.iter().copied()),
+        }
+    }
+
+    /// Approximates the bounding sphere of the mesh using Ritter's algorithm.
+    ///
+    /// The result is not guaranteed to be minimal, but always contains every vertex.
+    pub fn bounding_sphere(&self) -> Sphere {
+        let Some(&first) = self.vertices.first() else {
+            return Sphere::new(Vec3::ZERO, 0.0);
+        };
+        let farthest_from = |from: Vec3| {
+            self.vertices
+                .iter()
+                .copied()
+                .max_by(|a, b| {
+                    a.distance_squared(from)
+                        .total_cmp(&b.distance_squared(from))
+                })
+                .unwrap_or(from)
+        };
+
+        let a = farthest_from(first);
+        let b = farthest_from(a);
+        let mut origin = (a + b) * 0.5;
+        let mut radius = a.distance(b) * 0.5;
+
+        for &v in &self.vertices {
+            let distance = v.distance(origin);
+            if distance > radius {
+                let new_radius = (radius + distance) * 0.5;
+                origin += (v - origin) * ((new_radius - radius) / distance);
+                radius = new_radius;
+            }
+        }
+
+        Sphere::new(origin, radius)
+    }
+
+    /// Reverses the winding order of every face, keeping each vertex's UV and color with it
+    pub fn flip_winding(&mut self) {
+        for face in &mut self.faces {
+            std::mem::swap(&mut face.vertex_ids.1, &mut face.vertex_ids.2);
+            std::mem::swap(&mut face.uvs.1, &mut face.uvs.2);
+            std::mem::swap(&mut face.colors.1, &mut face.colors.2);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::vec2;
+
+    #[test]
+    fn flip_winding() {
+        let mut mesh = StaticMesh {
+            name: "mesh".to_string(),
+            vertices: vec![Vec3::ZERO, Vec3::X, Vec3::Y],
+            faces: vec![StaticMeshFace {
+                material: "mat".to_string(),
+                vertex_ids: (0, 1, 2),
+                uvs: (vec2(0.0, 0.0), vec2(1.0, 0.0), vec2(0.0, 1.0)),
+                colors: (Color::<f32>::ONE, Color::<f32>::ONE, Color::<f32>::ONE),
+            }],
+            vertex_colors: None,
+        };
+
+        mesh.flip_winding();
+
+        let face = &mesh.faces()[0];
+        assert_eq!(face.vertex_ids, (0, 2, 1));
+        assert_eq!(face.uvs, (vec2(0.0, 0.0), vec2(0.0, 1.0), vec2(1.0, 0.0)));
+    }
+
+    #[test]
+    fn bounding_sphere_contains_vertices() {
+        let vertices = vec![
+            Vec3::new(-1.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 3.0, 0.5),
+            Vec3::new(0.2, -0.4, -2.0),
+        ];
+        let mesh = StaticMesh {
+            name: "mesh".to_string(),
+            vertices: vertices.clone(),
+            faces: vec![],
+            vertex_colors: None,
+        };
+
+        let sphere = mesh.bounding_sphere();
+        for v in vertices {
+            assert!(v.distance(sphere.origin) <= sphere.radius + 1e-5);
+        }
+    }
+
+    #[test]
+    fn bounding_box() {
+        let mut mesh = StaticMesh {
+            name: "mesh".to_string(),
+            vertices: vec![
+                Vec3::new(-1.0, 0.0, 0.0),
+                Vec3::new(1.0, 0.0, 0.0),
+                Vec3::new(0.0, 3.0, 0.5),
+                Vec3::new(0.2, -0.4, -2.0),
+            ],
+            faces: vec![],
+            vertex_colors: None,
+        };
+
+        let aabb = mesh.bounding_box();
+        assert_eq!(aabb.min, Vec3::new(-1.0, -0.4, -2.0));
+        assert_eq!(aabb.max, Vec3::new(1.0, 3.0, 0.5));
+
+        mesh.vertices.clear();
+        assert_eq!(mesh.bounding_box(), AABB::new(Vec3::ZERO, Vec3::ZERO));
+    }
 }
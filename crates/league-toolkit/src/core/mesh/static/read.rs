@@ -18,10 +18,12 @@ impl StaticMesh {
         let major = reader.read_u16::<LE>()?;
         let minor = reader.read_u16::<LE>()?;
 
-        // there are versions [2][1] and [1][1] as well
-        if major != 2 && major != 3 && minor != 1 {
-            return Err(ParseError::InvalidFileVersion(major, minor));
-        }
+        // 1.1, 2.1 and 3.1 share a layout, 3.2 adds vertex colors
+        let has_vertex_color_flag = match (major, minor) {
+            (1, 1) | (2, 1) | (3, 1) => false,
+            (3, 2) => true,
+            _ => return Err(ParseError::UnsupportedStaticMeshVersion(major, minor)),
+        };
 
         let name = reader.read_padded_string::<LE, 128>()?;
 
@@ -31,9 +33,9 @@ impl StaticMesh {
         let _flags = reader.read_u32::<LE>()?; // TODO (alan): handle StaticMeshFlags
         let _bounding_box = reader.read_aabb::<LE>()?;
 
-        let has_vertex_colors = match (major, minor) {
-            (3.., 2..) => reader.read_i32::<LE>()? == 1,
-            _ => false,
+        let has_vertex_colors = match has_vertex_color_flag {
+            true => reader.read_i32::<LE>()? == 1,
+            false => false,
         };
 
         // TODO (alan): try some byte reinterp here
@@ -70,3 +72,79 @@ impl StaticMesh {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use byteorder::WriteBytesExt;
+    use std::io::{Cursor, Write};
+
+    fn mesh_bytes(major: u16, minor: u16, vertex_colors: bool) -> Vec<u8> {
+        let mut w = Vec::new();
+        w.write_all(MAGIC).unwrap();
+        w.write_u16::<LE>(major).unwrap();
+        w.write_u16::<LE>(minor).unwrap();
+        let mut name = [0; 128];
+        name[..4].copy_from_slice(b"mesh");
+        w.write_all(&name).unwrap();
+        w.write_i32::<LE>(3).unwrap(); // vertex count
+        w.write_i32::<LE>(1).unwrap(); // face count
+        w.write_u32::<LE>(0).unwrap(); // flags
+        for v in [0.0, 0.0, 0.0, 1.0, 1.0, 0.0] {
+            w.write_f32::<LE>(v).unwrap(); // bounding box
+        }
+        if (major, minor) == (3, 2) {
+            w.write_i32::<LE>(vertex_colors as i32).unwrap();
+        }
+        for v in [0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0] {
+            w.write_f32::<LE>(v).unwrap();
+        }
+        if vertex_colors {
+            for _ in 0..3 * 4 {
+                w.write_f32::<LE>(1.0).unwrap();
+            }
+        }
+        for v in [0.5, 0.5, 0.0] {
+            w.write_f32::<LE>(v).unwrap(); // central point
+        }
+        for i in 0..3 {
+            w.write_u32::<LE>(i).unwrap();
+        }
+        let mut material = [0; 64];
+        material[..3].copy_from_slice(b"mat");
+        w.write_all(&material).unwrap();
+        for v in [0.0, 1.0, 0.0, 0.0, 0.0, 1.0] {
+            w.write_f32::<LE>(v).unwrap();
+        }
+        w
+    }
+
+    #[test]
+    fn read_known_versions() {
+        for (major, minor, vertex_colors) in [
+            (1, 1, false),
+            (2, 1, false),
+            (3, 1, false),
+            (3, 2, false),
+            (3, 2, true),
+        ] {
+            let mesh =
+                StaticMesh::from_reader(&mut Cursor::new(mesh_bytes(major, minor, vertex_colors)))
+                    .unwrap_or_else(|e| panic!("failed to read v{major}.{minor}: {e}"));
+            assert_eq!(mesh.name(), "mesh");
+            assert_eq!(mesh.vertices().len(), 3);
+            assert_eq!(mesh.faces().len(), 1);
+            assert_eq!(mesh.faces()[0].material, "mat");
+            assert_eq!(mesh.vertex_colors().is_some(), vertex_colors);
+        }
+    }
+
+    #[test]
+    fn read_unsupported_version() {
+        let err = StaticMesh::from_reader(&mut Cursor::new(mesh_bytes(2, 2, false))).unwrap_err();
+        assert!(matches!(
+            err,
+            ParseError::UnsupportedStaticMeshVersion(2, 2)
+        ));
+    }
+}
@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+
+use super::BinTreeObject;
+use crate::core::meta::{property::value::PropertyValueEnum, BinProperty};
+
+pub struct BinTreeObjectBuilder {
+    path_hash: u32,
+    class_hash: u32,
+    properties: HashMap<u32, BinProperty>,
+}
+
+impl BinTreeObjectBuilder {
+    pub fn new(path_hash: u32, class_hash: u32) -> Self {
+        Self {
+            path_hash,
+            class_hash,
+            properties: HashMap::new(),
+        }
+    }
+
+    pub fn property(mut self, name_hash: u32, value: impl Into<PropertyValueEnum>) -> Self {
+        self.add_property(name_hash, value);
+        self
+    }
+
+    /// Adds the property only if `condition` is true
+    pub fn property_if(
+        self,
+        condition: bool,
+        name_hash: u32,
+        value: impl Into<PropertyValueEnum>,
+    ) -> Self {
+        match condition {
+            true => self.property(name_hash, value),
+            false => self,
+        }
+    }
+
+    pub fn add_property(&mut self, name_hash: u32, value: impl Into<PropertyValueEnum>) {
+        self.properties.insert(
+            name_hash,
+            BinProperty {
+                name_hash,
+                value: value.into(),
+            },
+        );
+    }
+
+    pub fn build(self) -> BinTreeObject {
+        BinTreeObject {
+            path_hash: self.path_hash,
+            class_hash: self.class_hash,
+            properties: self.properties,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::meta::property::value::{BoolValue, StringValue};
+
+    #[test]
+    fn property_if() {
+        let object = BinTreeObject::builder(1, 2)
+            .property(10, StringValue("a".to_string()))
+            .property_if(true, 11, BoolValue(true))
+            .property_if(false, 12, BoolValue(false))
+            .build();
+
+        assert_eq!(object.path_hash, 1);
+        assert_eq!(object.class_hash, 2);
+        assert_eq!(object.property_count(), 2);
+        assert!(object.has_property(11));
+        assert!(!object.has_property(12));
+        assert_eq!(
+            object.property(10).map(|p| &p.value),
+            Some(&StringValue("a".to_string()).into())
+        );
+    }
+}
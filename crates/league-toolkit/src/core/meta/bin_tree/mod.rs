@@ -1,7 +1,16 @@
 use std::collections::HashMap;
 
+mod builder;
 mod object;
-use super::error::ParseError;
+use super::{
+    error::ParseError,
+    property::{
+        hash_unordered, rename_hash, rename_property_hashes,
+        value::{EmbeddedValue, PropertyValueEnum, UnorderedContainerValue},
+        BinPropertyKind,
+    },
+};
+pub use builder::*;
 pub use object::*;
 
 pub mod read;
@@ -22,6 +31,19 @@ pub struct BinTree {
     data_overrides: Vec<()>,
 }
 
+/// Float properties are compared by value, so a tree containing a NaN is not equal to itself.
+impl Eq for BinTree {}
+
+impl std::hash::Hash for BinTree {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.is_override.hash(state);
+        self.version.hash(state);
+        hash_unordered(self.objects.values(), state);
+        self.dependencies.hash(state);
+        self.data_overrides.hash(state);
+    }
+}
+
 impl BinTree {
     pub fn new(
         objects: impl IntoIterator<Item = BinTreeObject>,
@@ -38,4 +60,187 @@ impl BinTree {
             data_overrides: Vec::new(),
         }
     }
+
+    /// Inserts every object, replacing any existing object with the same path hash.
+    ///
+    /// Returns `(replaced, inserted)` counts.
+    pub fn extend_objects(
+        &mut self,
+        objects: impl IntoIterator<Item = BinTreeObject>,
+    ) -> (usize, usize) {
+        let (mut replaced, mut inserted) = (0, 0);
+        for object in objects {
+            match self.objects.insert(object.path_hash, object) {
+                Some(_) => replaced += 1,
+                None => inserted += 1,
+            }
+        }
+        (replaced, inserted)
+    }
+
+    /// Iterates over the top level properties of every object as
+    /// `(object path hash, object class hash, property)`, without recursing into nested values.
+    pub fn iter_properties_flat(&self) -> impl Iterator<Item = (u32, u32, &super::BinProperty)> {
+        self.objects.values().flat_map(|object| {
+            object
+                .properties
+                .values()
+                .map(|property| (object.path_hash, object.class_hash, property))
+        })
+    }
+
+    /// Counts every property value in the tree by kind, including values nested in
+    /// containers, maps, optionals and structs.
+    pub fn property_kind_histogram(&self) -> HashMap<BinPropertyKind, usize> {
+        let mut histogram = HashMap::new();
+        for property in self.objects.values().flat_map(|o| o.properties.values()) {
+            count_kinds(&property.value, &mut histogram);
+        }
+        histogram
+    }
+
+    /// Replaces every object path, class and property name hash in the tree found in `table`
+    /// with its mapped value.
+    pub fn rename_all_hashes(&mut self, table: &HashMap<u32, u32>) {
+        self.objects = self
+            .objects
+            .drain()
+            .map(|(_, mut object)| {
+                rename_hash(&mut object.path_hash, table);
+                rename_hash(&mut object.class_hash, table);
+                rename_property_hashes(&mut object.properties, table);
+                (object.path_hash, object)
+            })
+            .collect();
+    }
+}
+
+fn count_kinds(value: &PropertyValueEnum, histogram: &mut HashMap<BinPropertyKind, usize>) {
+    *histogram.entry(value.kind()).or_default() += 1;
+
+    match value {
+        PropertyValueEnum::Container(container)
+        | PropertyValueEnum::UnorderedContainer(UnorderedContainerValue(container)) => {
+            for item in &container.items {
+                count_kinds(item, histogram);
+            }
+        }
+        PropertyValueEnum::Struct(value) | PropertyValueEnum::Embedded(EmbeddedValue(value)) => {
+            for property in value.properties.values() {
+                count_kinds(&property.value, histogram);
+            }
+        }
+        PropertyValueEnum::Optional(optional) => {
+            if let Some(inner) = optional.as_ref() {
+                count_kinds(inner, histogram);
+            }
+        }
+        PropertyValueEnum::Map(map) => {
+            for (key, value) in &map.entries {
+                count_kinds(&key.0, histogram);
+                count_kinds(value, histogram);
+            }
+        }
+        _ => {}
+    }
+}
+
+impl Extend<BinTreeObject> for BinTree {
+    fn extend<T: IntoIterator<Item = BinTreeObject>>(&mut self, iter: T) {
+        self.extend_objects(iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::meta::property::value::{
+        ContainerValue, MapValue, PropertyValueUnsafeEq, StringValue, StructValue, U32Value,
+    };
+    use crate::core::meta::BinProperty;
+
+    #[test]
+    fn extend_objects() {
+        let object = |path_hash, value: &str| {
+            BinTreeObject::builder(path_hash, 0xc1a55)
+                .property(1, StringValue(value.to_string()))
+                .build()
+        };
+        let mut tree = BinTree::new([object(1, "old"), object(2, "kept")], []);
+
+        let counts = tree.extend_objects([object(1, "new"), object(3, "added")]);
+        assert_eq!(counts, (1, 1));
+
+        assert_eq!(tree.objects.len(), 3);
+        for (path_hash, value) in [(1, "new"), (2, "kept"), (3, "added")] {
+            assert_eq!(
+                tree.objects[&path_hash].property(1).map(|p| &p.value),
+                Some(&StringValue(value.to_string()).into())
+            );
+        }
+
+        tree.extend([object(4, "extended")]);
+        assert!(tree.objects.contains_key(&4));
+    }
+
+    #[test]
+    fn iter_properties_flat() {
+        let nested = StructValue {
+            class_hash: 0x57c7,
+            properties: HashMap::from([(
+                20,
+                BinProperty {
+                    name_hash: 20,
+                    value: U32Value(20).into(),
+                },
+            )]),
+        };
+        let tree = BinTree::new(
+            [
+                BinTreeObject::builder(1, 0xa)
+                    .property(10, U32Value(10))
+                    .property(11, nested.clone())
+                    .property(
+                        12,
+                        ContainerValue {
+                            item_kind: BinPropertyKind::Struct,
+                            items: vec![nested.clone().into()],
+                        },
+                    )
+                    .property(
+                        13,
+                        MapValue {
+                            key_kind: BinPropertyKind::U32,
+                            value_kind: BinPropertyKind::Struct,
+                            entries: HashMap::from([(
+                                PropertyValueUnsafeEq(U32Value(0).into()),
+                                nested.into(),
+                            )]),
+                        },
+                    )
+                    .build(),
+                BinTreeObject::builder(2, 0xb)
+                    .property(30, U32Value(30))
+                    .build(),
+            ],
+            [],
+        );
+
+        // only top level properties - the nested struct's property 20 is never yielded
+        let mut properties = tree
+            .iter_properties_flat()
+            .map(|(path_hash, class_hash, property)| (path_hash, class_hash, property.name_hash))
+            .collect::<Vec<_>>();
+        properties.sort();
+        assert_eq!(
+            properties,
+            [
+                (1, 0xa, 10),
+                (1, 0xa, 11),
+                (1, 0xa, 12),
+                (1, 0xa, 13),
+                (2, 0xb, 30)
+            ]
+        );
+    }
 }
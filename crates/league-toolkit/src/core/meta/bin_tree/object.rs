@@ -1,8 +1,15 @@
-use std::{collections::HashMap, io};
+use std::{
+    collections::HashMap,
+    hash::{Hash, Hasher},
+    io,
+};
 
 use io_ext::{measure, window};
 
-use super::{super::BinProperty, ParseError};
+use super::{
+    super::{property::hash_unordered, BinProperty},
+    BinTreeObjectBuilder, ParseError,
+};
 use byteorder::{ReadBytesExt, WriteBytesExt, LE};
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -13,7 +20,35 @@ pub struct BinTreeObject {
     pub properties: HashMap<u32, BinProperty>,
 }
 
+/// Float properties are compared by value, so an object containing a NaN is not equal to itself.
+impl Eq for BinTreeObject {}
+
+impl Hash for BinTreeObject {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.path_hash.hash(state);
+        self.class_hash.hash(state);
+        hash_unordered(self.properties.values(), state);
+    }
+}
+
 impl BinTreeObject {
+    pub fn builder(path_hash: u32, class_hash: u32) -> BinTreeObjectBuilder {
+        BinTreeObjectBuilder::new(path_hash, class_hash)
+    }
+
+    /// Looks up a property by its name hash (properties are keyed by name hash, so this is O(1))
+    pub fn property(&self, name_hash: u32) -> Option<&BinProperty> {
+        self.properties.get(&name_hash)
+    }
+
+    pub fn has_property(&self, name_hash: u32) -> bool {
+        self.properties.contains_key(&name_hash)
+    }
+
+    pub fn property_count(&self) -> usize {
+        self.properties.len()
+    }
+
     pub fn from_reader<R: io::Read + io::Seek + ?Sized>(
         reader: &mut R,
         class_hash: u32,
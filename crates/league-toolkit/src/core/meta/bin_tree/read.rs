@@ -1,4 +1,4 @@
-use std::{collections::HashMap, io};
+use std::{collections::HashMap, fs::File, io, path::Path};
 
 use crate::core::meta::ParseError;
 
@@ -9,6 +9,19 @@ use io_ext::ReaderExt;
 impl BinTree {
     pub const PROP: u32 = u32::from_le_bytes(*b"PROP");
     pub const PTCH: u32 = u32::from_le_bytes(*b"PTCH");
+
+    /// Reads a bin file from disk, attaching the path to any error
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ParseError> {
+        let path = path.as_ref();
+        File::open(path)
+            .map_err(ParseError::from)
+            .and_then(|file| Self::from_reader(&mut io::BufReader::new(file)))
+            .map_err(|e| ParseError::File {
+                path: path.to_path_buf(),
+                source: Box::new(e),
+            })
+    }
+
     pub fn from_reader<R: io::Read + std::io::Seek + ?Sized>(
         reader: &mut R,
     ) -> Result<Self, ParseError> {
@@ -15,9 +15,9 @@ pub enum ParseError {
     #[error("Invalid size - expected {0}, got {1} bytes")]
     InvalidSize(u64, u64),
 
-    #[error("Container type {0:?} cannot be nested!")]
+    #[error("Container type '{0}' cannot be nested!")]
     InvalidNesting(BinPropertyKind),
-    #[error("Invalid map key type {0:?}, only primitive types can be used as keys.")]
+    #[error("Invalid map key type '{0}', only primitive types can be used as keys.")]
     InvalidKeyType(BinPropertyKind),
 
     #[error(transparent)]
@@ -26,4 +26,11 @@ pub enum ParseError {
     IOError(#[from] std::io::Error),
     #[error("UTF-8 Error - {0}")]
     Utf8Error(#[from] std::str::Utf8Error),
+
+    #[error("Failed to read '{}'", path.display())]
+    File {
+        path: std::path::PathBuf,
+        #[source]
+        source: Box<ParseError>,
+    },
 }
@@ -1,6 +1,10 @@
 use num_enum::{IntoPrimitive, TryFromPrimitive};
-use std::io;
-use value::PropertyValueEnum;
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    io,
+};
+use value::{EmbeddedValue, PropertyValueEnum, UnorderedContainerValue};
 
 use super::ParseError;
 
@@ -103,6 +107,59 @@ impl BinPropertyKind {
         matches!(self, Container | UnorderedContainer | Optional | Map)
     }
 
+    /// The size of a value of this kind on disk (excluding the property header),
+    /// or `None` if the size depends on the value itself.
+    pub fn fixed_size(&self) -> Option<usize> {
+        use BinPropertyKind::*;
+        Some(match self {
+            None => 0,
+            Bool | I8 | U8 | BitBool => 1,
+            I16 | U16 => 2,
+            I32 | U32 | F32 | Color | Hash | ObjectLink => 4,
+            I64 | U64 | Vector2 | WadChunkLink => 8,
+            Vector3 => 12,
+            Vector4 => 16,
+            Matrix44 => 64,
+            String | Container | UnorderedContainer | Struct | Embedded | Optional | Map => {
+                return Option::None
+            }
+        })
+    }
+
+    /// The name of this kind in the ritobin text format
+    pub fn type_name(&self) -> &'static str {
+        use BinPropertyKind::*;
+        match self {
+            None => "none",
+            Bool => "bool",
+            I8 => "i8",
+            U8 => "u8",
+            I16 => "i16",
+            U16 => "u16",
+            I32 => "i32",
+            U32 => "u32",
+            I64 => "i64",
+            U64 => "u64",
+            F32 => "f32",
+            Vector2 => "vec2",
+            Vector3 => "vec3",
+            Vector4 => "vec4",
+            Matrix44 => "mtx44",
+            Color => "rgba",
+            String => "string",
+            Hash => "hash",
+            WadChunkLink => "file",
+            Container => "list",
+            UnorderedContainer => "list2",
+            Struct => "pointer",
+            Embedded => "embed",
+            ObjectLink => "link",
+            Optional => "option",
+            Map => "map",
+            BitBool => "flag",
+        }
+    }
+
     pub fn read<R: io::Read + std::io::Seek + ?Sized>(
         self,
         reader: &mut R,
@@ -112,8 +169,14 @@ impl BinPropertyKind {
     }
 }
 
+impl std::fmt::Display for BinPropertyKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.type_name())
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Hash, Debug)]
 pub struct BinProperty {
     pub name_hash: u32,
     #[cfg_attr(feature = "serde", serde(flatten))]
@@ -152,4 +215,249 @@ impl BinProperty {
     pub fn size(&self) -> usize {
         5 + self.value.size_no_header()
     }
+
+    /// Replaces every property name, class and object link hash (including nested ones) found in
+    /// `table` with its mapped value.
+    pub fn rename_hash_with_table(&mut self, table: &HashMap<u32, u32>) {
+        rename_hash(&mut self.name_hash, table);
+        rename_value_hashes(&mut self.value, table);
+    }
+}
+
+/// Hashes the items of an unordered collection (eg. a `HashMap`), so that the result doesn't
+/// depend on iteration order
+pub(crate) fn hash_unordered<T: Hash, H: Hasher>(
+    items: impl IntoIterator<Item = T>,
+    state: &mut H,
+) {
+    let (count, sum) = items
+        .into_iter()
+        .fold((0usize, 0u64), |(count, sum), item| {
+            let mut hasher = DefaultHasher::new();
+            item.hash(&mut hasher);
+            (count + 1, sum.wrapping_add(hasher.finish()))
+        });
+    count.hash(state);
+    sum.hash(state);
+}
+
+pub(crate) fn rename_hash(hash: &mut u32, table: &HashMap<u32, u32>) {
+    if let Some(&renamed) = table.get(hash) {
+        *hash = renamed;
+    }
+}
+
+pub(crate) fn rename_property_hashes(
+    properties: &mut HashMap<u32, BinProperty>,
+    table: &HashMap<u32, u32>,
+) {
+    *properties = properties
+        .drain()
+        .map(|(_, mut property)| {
+            property.rename_hash_with_table(table);
+            (property.name_hash, property)
+        })
+        .collect();
+}
+
+fn rename_value_hashes(value: &mut PropertyValueEnum, table: &HashMap<u32, u32>) {
+    match value {
+        PropertyValueEnum::ObjectLink(link) => rename_hash(&mut link.0, table),
+        PropertyValueEnum::Container(container)
+        | PropertyValueEnum::UnorderedContainer(UnorderedContainerValue(container)) => {
+            for item in &mut container.items {
+                rename_value_hashes(item, table);
+            }
+        }
+        PropertyValueEnum::Struct(value) | PropertyValueEnum::Embedded(EmbeddedValue(value)) => {
+            rename_hash(&mut value.class_hash, table);
+            rename_property_hashes(&mut value.properties, table);
+        }
+        PropertyValueEnum::Optional(optional) => {
+            if let Some(inner) = &mut optional.1 {
+                rename_value_hashes(inner, table);
+            }
+        }
+        PropertyValueEnum::Map(map) => {
+            map.entries = map
+                .entries
+                .drain()
+                .map(|(mut key, mut value)| {
+                    rename_value_hashes(&mut key.0, table);
+                    rename_value_hashes(&mut value, table);
+                    (key, value)
+                })
+                .collect();
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, io::Cursor};
+
+    use glam::{Mat4, Vec3};
+
+    use super::{value::*, BinProperty, BinPropertyKind};
+
+    fn struct_value(class_hash: u32, properties: Vec<BinProperty>) -> StructValue {
+        StructValue {
+            class_hash,
+            properties: properties.into_iter().map(|p| (p.name_hash, p)).collect(),
+        }
+    }
+
+    #[test]
+    fn size_matches_bytes_written() {
+        let values: Vec<PropertyValueEnum> = vec![
+            NoneValue.into(),
+            BoolValue(true).into(),
+            U16Value(7).into(),
+            F32Value(1.5).into(),
+            Vector3Value(Vec3::ONE).into(),
+            Matrix44Value(Mat4::IDENTITY).into(),
+            StringValue("Characters/Annie/Skins/Skin0".to_string()).into(),
+            HashValue(0xdeadbeef).into(),
+            WadChunkLinkValue(42).into(),
+            ContainerValue {
+                item_kind: BinPropertyKind::String,
+                items: vec![
+                    StringValue("a".to_string()).into(),
+                    StringValue("bcd".to_string()).into(),
+                ],
+            }
+            .into(),
+            UnorderedContainerValue(ContainerValue {
+                item_kind: BinPropertyKind::U32,
+                items: vec![U32Value(1).into(), U32Value(2).into()],
+            })
+            .into(),
+            OptionalValue(BinPropertyKind::I32, Some(Box::new(I32Value(-1).into()))).into(),
+            OptionalValue(BinPropertyKind::I32, None).into(),
+            MapValue {
+                key_kind: BinPropertyKind::Hash,
+                value_kind: BinPropertyKind::String,
+                entries: HashMap::from([
+                    (
+                        PropertyValueUnsafeEq(HashValue(1).into()),
+                        StringValue("one".to_string()).into(),
+                    ),
+                    (
+                        PropertyValueUnsafeEq(HashValue(2).into()),
+                        StringValue("two".to_string()).into(),
+                    ),
+                ]),
+            }
+            .into(),
+            struct_value(0, vec![]).into(),
+            EmbeddedValue(struct_value(
+                0x1234,
+                vec![
+                    BinProperty {
+                        name_hash: 1,
+                        value: U8Value(1).into(),
+                    },
+                    BinProperty {
+                        name_hash: 2,
+                        value: struct_value(
+                            0x5678,
+                            vec![BinProperty {
+                                name_hash: 3,
+                                value: StringValue("nested".to_string()).into(),
+                            }],
+                        )
+                        .into(),
+                    },
+                ],
+            ))
+            .into(),
+        ];
+
+        for (i, value) in values.into_iter().enumerate() {
+            let prop = BinProperty {
+                name_hash: i as u32,
+                value,
+            };
+            let mut cursor = Cursor::new(Vec::new());
+            prop.to_writer(&mut cursor).unwrap();
+
+            assert_eq!(
+                prop.size(),
+                cursor.get_ref().len(),
+                "size mismatch for {:?}",
+                prop.value.kind()
+            );
+        }
+    }
+
+    #[test]
+    fn map_round_trip() {
+        let prop = BinProperty {
+            name_hash: 0x10,
+            value: MapValue {
+                key_kind: BinPropertyKind::U32,
+                value_kind: BinPropertyKind::F32,
+                entries: HashMap::from([(
+                    PropertyValueUnsafeEq(U32Value(5).into()),
+                    F32Value(0.5).into(),
+                )]),
+            }
+            .into(),
+        };
+        let mut cursor = Cursor::new(Vec::new());
+        prop.to_writer(&mut cursor).unwrap();
+        cursor.set_position(0);
+
+        assert_eq!(BinProperty::from_reader(&mut cursor, false).unwrap(), prop);
+    }
+
+    fn all_kinds() -> impl Iterator<Item = BinPropertyKind> {
+        (0..=u8::MAX).filter_map(|raw| BinPropertyKind::try_from(raw).ok())
+    }
+
+    #[test]
+    fn fixed_size() {
+        use crate::core::meta::traits::PropertyValue as _;
+
+        assert_eq!(all_kinds().count(), 27);
+        for kind in all_kinds() {
+            match kind.fixed_size() {
+                Some(size) => {
+                    let value = kind.read(&mut Cursor::new(vec![0; 64]), false).unwrap();
+                    assert_eq!(value.size_no_header(), size, "{kind:?}");
+                }
+                None => assert!(
+                    kind == BinPropertyKind::String
+                        || kind.is_container()
+                        || matches!(kind, BinPropertyKind::Struct | BinPropertyKind::Embedded),
+                    "{kind:?}"
+                ),
+            }
+        }
+    }
+
+    #[test]
+    fn type_name() {
+        for (kind, name) in [
+            (BinPropertyKind::None, "none"),
+            (BinPropertyKind::U32, "u32"),
+            (BinPropertyKind::F32, "f32"),
+            (BinPropertyKind::Vector3, "vec3"),
+            (BinPropertyKind::Matrix44, "mtx44"),
+            (BinPropertyKind::Color, "rgba"),
+            (BinPropertyKind::WadChunkLink, "file"),
+            (BinPropertyKind::Container, "list"),
+            (BinPropertyKind::UnorderedContainer, "list2"),
+            (BinPropertyKind::Struct, "pointer"),
+            (BinPropertyKind::Embedded, "embed"),
+            (BinPropertyKind::ObjectLink, "link"),
+            (BinPropertyKind::Optional, "option"),
+            (BinPropertyKind::Map, "map"),
+            (BinPropertyKind::BitBool, "flag"),
+        ] {
+            assert_eq!(kind.type_name(), name);
+            assert_eq!(kind.to_string(), name);
+        }
+    }
 }
@@ -1,18 +1,20 @@
 use std::{collections::HashMap, hash::Hash, io};
 
 use crate::core::meta::{
-    property::BinPropertyKind,
+    property::{hash_unordered, BinPropertyKind},
     traits::{PropertyValue, ReadProperty, ReaderExt, WriteProperty, WriterExt},
     ParseError,
 };
 use byteorder::{ReadBytesExt, WriteBytesExt, LE};
 use io_ext::{measure, window};
 
-use super::PropertyValueEnum;
+use super::{EmbeddedValue, PropertyValueEnum, UnorderedContainerValue};
 
-// FIXME (alan): do with hash here what we do with Eq
+/// Floats are hashed by their bits (with `-0.0` treated as `0.0`, to agree with `PartialEq`),
+/// and maps/structs are hashed independently of their iteration order.
 impl Hash for PropertyValueEnum {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
         match self {
             PropertyValueEnum::None(i) => i.hash(state),
             PropertyValueEnum::Bool(i) => i.hash(state),
@@ -25,7 +27,43 @@ impl Hash for PropertyValueEnum {
             PropertyValueEnum::I64(i) => i.hash(state),
             PropertyValueEnum::U64(i) => i.hash(state),
             PropertyValueEnum::BitBool(i) => i.hash(state),
-            _ => std::mem::discriminant(self).hash(state),
+            PropertyValueEnum::F32(i) => hash_floats(&[i.0], state),
+            PropertyValueEnum::Vector2(i) => hash_floats(&i.0.to_array(), state),
+            PropertyValueEnum::Vector3(i) => hash_floats(&i.0.to_array(), state),
+            PropertyValueEnum::Vector4(i) => hash_floats(&i.0.to_array(), state),
+            PropertyValueEnum::Matrix44(i) => hash_floats(&i.0.to_cols_array(), state),
+            PropertyValueEnum::Color(i) => [i.0.r, i.0.g, i.0.b, i.0.a].hash(state),
+            PropertyValueEnum::String(i) => i.0.hash(state),
+            PropertyValueEnum::Hash(i) => i.hash(state),
+            PropertyValueEnum::WadChunkLink(i) => i.hash(state),
+            PropertyValueEnum::ObjectLink(i) => i.hash(state),
+            PropertyValueEnum::Container(i)
+            | PropertyValueEnum::UnorderedContainer(UnorderedContainerValue(i)) => {
+                i.item_kind.hash(state);
+                i.items.hash(state);
+            }
+            PropertyValueEnum::Struct(i) | PropertyValueEnum::Embedded(EmbeddedValue(i)) => {
+                i.class_hash.hash(state);
+                hash_unordered(i.properties.values(), state);
+            }
+            PropertyValueEnum::Optional(i) => {
+                i.0.hash(state);
+                i.1.hash(state);
+            }
+            PropertyValueEnum::Map(i) => {
+                i.key_kind.hash(state);
+                i.value_kind.hash(state);
+                hash_unordered(&i.entries, state);
+            }
+        }
+    }
+}
+
+fn hash_floats<H: std::hash::Hasher>(values: &[f32], state: &mut H) {
+    for value in values {
+        match *value == 0.0 {
+            true => 0u32.hash(state),
+            false => value.to_bits().hash(state),
         }
     }
 }
@@ -117,7 +155,7 @@ impl WriteProperty for MapValue {
         writer.write_u32::<LE>(0)?;
 
         let (size, _) = measure(writer, |writer| {
-            writer.write_u16::<LE>(self.entries.len() as _)?;
+            writer.write_u32::<LE>(self.entries.len() as _)?;
 
             for (k, v) in self.entries.iter() {
                 k.0.to_writer(writer)?;
@@ -12,6 +12,27 @@ use io_ext::{ReaderExt as _, WriterExt as _};
 #[derive(Clone, PartialEq, Debug)]
 pub struct OptionalValue(pub BinPropertyKind, pub Option<Box<PropertyValueEnum>>);
 
+impl OptionalValue {
+    pub fn is_some(&self) -> bool {
+        self.1.is_some()
+    }
+
+    pub fn is_none(&self) -> bool {
+        self.1.is_none()
+    }
+
+    pub fn as_ref(&self) -> Option<&PropertyValueEnum> {
+        self.1.as_deref()
+    }
+
+    /// # Panics
+    /// If the value is none.
+    pub fn unwrap(&self) -> &PropertyValueEnum {
+        self.as_ref()
+            .expect("called `OptionalValue::unwrap()` on a none value")
+    }
+}
+
 impl PropertyValue for OptionalValue {
     fn size_no_header(&self) -> usize {
         2 + match &self.1 {
@@ -60,3 +81,34 @@ impl WriteProperty for OptionalValue {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::meta::property::value::I32Value;
+
+    #[test]
+    fn some() {
+        let value = OptionalValue(BinPropertyKind::I32, Some(Box::new(I32Value(-1).into())));
+
+        assert!(value.is_some());
+        assert!(!value.is_none());
+        assert_eq!(value.as_ref(), Some(&I32Value(-1).into()));
+        assert_eq!(value.unwrap(), &I32Value(-1).into());
+    }
+
+    #[test]
+    fn none() {
+        let value = OptionalValue(BinPropertyKind::I32, None);
+
+        assert!(!value.is_some());
+        assert!(value.is_none());
+        assert_eq!(value.as_ref(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "on a none value")]
+    fn unwrap_none() {
+        OptionalValue(BinPropertyKind::I32, None).unwrap();
+    }
+}
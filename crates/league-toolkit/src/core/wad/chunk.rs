@@ -1,4 +1,8 @@
-use std::io::{BufReader, Read};
+use std::{
+    collections::HashMap,
+    io::{BufReader, Read},
+    path::Path,
+};
 
 use byteorder::{ReadBytesExt as _, LE};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
@@ -40,7 +44,9 @@ impl WadChunk {
         let type_frame_count = reader.read_u8()?;
         let frame_count = type_frame_count >> 4;
         let compression_type = WadChunkCompression::try_from_primitive(type_frame_count & 0xF)
-            .expect("failed to read chunk compression");
+            .map_err(|e| WadError::InvalidChunkCompression {
+                compression: e.number,
+            })?;
 
         let is_duplicated = reader.read_u8()? == 1;
         let start_frame = reader.read_u16::<LE>()?;
@@ -77,4 +83,141 @@ impl WadChunk {
     pub fn checksum(&self) -> u64 {
         self.checksum
     }
+
+    /// Looks up the chunk's path in a hashtable
+    pub fn resolved_path<'a>(&self, table: &'a HashMap<u64, String>) -> Option<&'a str> {
+        table.get(&self.path_hash).map(String::as_str)
+    }
+
+    /// Whether the chunk's resolved path has the extension `ext` (with or without the leading
+    /// `.`), ignoring case. Chunks missing from the table never match.
+    pub fn has_extension(&self, ext: &str, table: &HashMap<u64, String>) -> bool {
+        let ext = ext.strip_prefix('.').unwrap_or(ext);
+        self.resolved_path(table)
+            .and_then(|path| Path::new(path).extension())
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| e.eq_ignore_ascii_case(ext))
+    }
+
+    /// Displays the chunk as `path [size compression]`, falling back to the path hash if the
+    /// path isn't in the hashtable
+    pub fn display<'a>(&self, hashtable: &'a HashMap<u64, String>) -> WadChunkDisplay<'a> {
+        WadChunkDisplay {
+            chunk: *self,
+            path: self.resolved_path(hashtable),
+        }
+    }
+
+    /// The uncompressed size formatted with a binary unit, eg. `4.2 MiB`
+    pub fn human_size(&self) -> String {
+        format_size(self.uncompressed_size)
+    }
+    /// The compressed size formatted with a binary unit, eg. `4.2 MiB`
+    pub fn human_compressed_size(&self) -> String {
+        format_size(self.compressed_size)
+    }
+}
+
+pub struct WadChunkDisplay<'a> {
+    chunk: WadChunk,
+    path: Option<&'a str>,
+}
+
+impl std::fmt::Display for WadChunkDisplay<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.path {
+            Some(path) => write!(f, "{path}")?,
+            None => write!(f, "{:#x}", self.chunk.path_hash)?,
+        }
+        write!(
+            f,
+            " [{} {:?}]",
+            self.chunk.human_size(),
+            self.chunk.compression_type
+        )
+    }
+}
+
+fn format_size(bytes: usize) -> String {
+    const UNITS: [&str; 3] = ["KiB", "MiB", "GiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn human_size() {
+        assert_eq!(format_size(0), "0 B");
+        assert_eq!(format_size(1023), "1023 B");
+        assert_eq!(format_size(1024), "1.0 KiB");
+        assert_eq!(format_size(4_404_019), "4.2 MiB");
+        assert_eq!(format_size(3 << 30), "3.0 GiB");
+        assert_eq!(format_size(2048 << 30), "2048.0 GiB");
+    }
+
+    fn chunk(path_hash: u64) -> WadChunk {
+        WadChunk {
+            path_hash,
+            data_offset: 0,
+            compressed_size: 1024,
+            uncompressed_size: 4301,
+            compression_type: WadChunkCompression::Zstd,
+            is_duplicated: false,
+            frame_count: 0,
+            start_frame: 0,
+            checksum: 0,
+        }
+    }
+
+    #[test]
+    fn resolved_path() {
+        let table = HashMap::from([(1, "assets/texture.DDS".to_string())]);
+
+        assert_eq!(chunk(1).resolved_path(&table), Some("assets/texture.DDS"));
+        assert_eq!(chunk(2).resolved_path(&table), None);
+    }
+
+    #[test]
+    fn has_extension() {
+        let table = HashMap::from([
+            (1, "assets/texture.DDS".to_string()),
+            (2, "data/no_extension".to_string()),
+        ]);
+
+        assert!(chunk(1).has_extension("dds", &table));
+        assert!(chunk(1).has_extension("Dds", &table));
+        assert!(chunk(1).has_extension(".dds", &table));
+        assert!(!chunk(1).has_extension("tex", &table));
+        assert!(!chunk(2).has_extension("", &table));
+        assert!(!chunk(3).has_extension("dds", &table));
+    }
+
+    #[test]
+    fn display() {
+        let chunk = chunk(0xdeadbeef);
+        let mut hashtable = HashMap::new();
+        assert_eq!(
+            chunk.display(&hashtable).to_string(),
+            "0xdeadbeef [4.2 KiB Zstd]"
+        );
+
+        hashtable.insert(0xdeadbeef, "path/to/file.bin".to_string());
+        assert_eq!(
+            chunk.display(&hashtable).to_string(),
+            "path/to/file.bin [4.2 KiB Zstd]"
+        );
+    }
 }
@@ -0,0 +1,73 @@
+use std::path::Path;
+
+/// The kind of content a wad chunk holds, as inferred from its path
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WadChunkKind {
+    PropertyBin,
+    Texture,
+    Dds,
+    SkinnedMesh,
+    Skeleton,
+    Animation,
+    MapGeometry,
+    Inibin,
+    Jpeg,
+    Png,
+    Webm,
+    Unknown,
+}
+
+impl WadChunkKind {
+    pub fn from_path(path: &str) -> WadChunkKind {
+        Path::new(path)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(Self::from_extension)
+            .unwrap_or(Self::Unknown)
+    }
+
+    /// Maps a file extension (without the leading `.`) to a chunk kind, ignoring case
+    pub fn from_extension(extension: &str) -> WadChunkKind {
+        match extension.to_ascii_lowercase().as_str() {
+            "bin" => Self::PropertyBin,
+            "tex" => Self::Texture,
+            "dds" => Self::Dds,
+            "skn" => Self::SkinnedMesh,
+            "skl" => Self::Skeleton,
+            "anm" => Self::Animation,
+            "mapgeo" => Self::MapGeometry,
+            "inibin" => Self::Inibin,
+            "jpg" | "jpeg" => Self::Jpeg,
+            "png" => Self::Png,
+            "webm" => Self::Webm,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_path() {
+        assert_eq!(
+            WadChunkKind::from_path("data/characters/annie/annie.bin"),
+            WadChunkKind::PropertyBin
+        );
+        assert_eq!(
+            WadChunkKind::from_path("ASSETS/Characters/Annie/Skins/Base/Annie.SKN"),
+            WadChunkKind::SkinnedMesh
+        );
+        assert_eq!(
+            WadChunkKind::from_path("data/maps/mapgeometry/map11/base.mapgeo"),
+            WadChunkKind::MapGeometry
+        );
+        assert_eq!(
+            WadChunkKind::from_path("no_extension"),
+            WadChunkKind::Unknown
+        );
+        assert_eq!(WadChunkKind::from_path("file.xyz"), WadChunkKind::Unknown);
+    }
+}
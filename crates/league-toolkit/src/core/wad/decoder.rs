@@ -1,4 +1,4 @@
-use std::io::{Read, Seek, SeekFrom};
+use std::io::{Cursor, Read, Seek, SeekFrom, Take};
 
 use super::{WadChunk, WadChunkCompression, WadError};
 use flate2::read::GzDecoder;
@@ -39,6 +39,34 @@ where
         }
     }
 
+    /// Returns a reader that decompresses the chunk while it is being read, instead of loading
+    /// the whole chunk into memory.
+    ///
+    /// `ZstdMulti` chunks are still decompressed up front.
+    pub fn stream_chunk<'a>(&'a mut self, chunk: &WadChunk) -> Result<impl Read + 'a, WadError> {
+        let reader: Box<dyn Read + 'a> = match chunk.compression_type {
+            WadChunkCompression::None => Box::new(self.raw_reader(chunk)?),
+            WadChunkCompression::GZip => Box::new(GzDecoder::new(self.raw_reader(chunk)?)),
+            WadChunkCompression::Satellite => {
+                return Err(WadError::Other(String::from(
+                    "satellite chunks are not supported",
+                )))
+            }
+            WadChunkCompression::Zstd => zstd_reader(self.raw_reader(chunk)?, chunk)?,
+            WadChunkCompression::ZstdMulti => {
+                Box::new(Cursor::new(self.decode_zstd_multi_chunk(chunk)?))
+            }
+        };
+
+        Ok(reader.take(chunk.uncompressed_size as u64))
+    }
+
+    fn raw_reader(&mut self, chunk: &WadChunk) -> Result<Take<&mut TSource>, WadError> {
+        self.source
+            .seek(SeekFrom::Start(chunk.data_offset as u64))?;
+        Ok((&mut *self.source).take(chunk.compressed_size as u64))
+    }
+
     fn decode_gzip_chunk(&mut self, chunk: &WadChunk) -> Result<Box<[u8]>, WadError> {
         self.source
             .seek(SeekFrom::Start(chunk.data_offset as u64))?;
@@ -106,3 +134,71 @@ where
         Ok(data.into_boxed_slice())
     }
 }
+
+#[cfg(feature = "zstd")]
+fn zstd_reader<'a>(
+    reader: impl Read + 'a,
+    _chunk: &WadChunk,
+) -> Result<Box<dyn Read + 'a>, WadError> {
+    Ok(Box::new(zstd::Decoder::new(reader)?))
+}
+
+#[cfg(feature = "ruzstd")]
+fn zstd_reader<'a>(
+    reader: impl Read + 'a,
+    chunk: &WadChunk,
+) -> Result<Box<dyn Read + 'a>, WadError> {
+    ruzstd::StreamingDecoder::new(reader)
+        .map(|decoder| Box::new(decoder) as Box<dyn Read + 'a>)
+        .map_err(|e| WadError::DecompressionFailure {
+            path_hash: chunk.path_hash,
+            reason: e.to_string(),
+        })
+}
+
+#[cfg(not(any(feature = "zstd", feature = "ruzstd")))]
+fn zstd_reader<'a>(
+    _reader: impl Read + 'a,
+    _chunk: &WadChunk,
+) -> Result<Box<dyn Read + 'a>, WadError> {
+    Err(WadError::Other(String::from("no zstd backend enabled")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::{write::GzEncoder, Compression};
+    use std::io::Write;
+
+    #[test]
+    fn stream_chunk() {
+        let data = b"some chunk data, some chunk data, some chunk data".repeat(4);
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&data).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let mut source = Cursor::new([b"header".as_slice(), &compressed, b"trailer"].concat());
+        let mut decoder = WadDecoder {
+            source: &mut source,
+        };
+        let chunk = WadChunk {
+            path_hash: 0,
+            data_offset: 6,
+            compressed_size: compressed.len(),
+            uncompressed_size: data.len(),
+            compression_type: WadChunkCompression::GZip,
+            is_duplicated: false,
+            frame_count: 0,
+            start_frame: 0,
+            checksum: 0,
+        };
+
+        let mut streamed = vec![];
+        decoder
+            .stream_chunk(&chunk)
+            .unwrap()
+            .read_to_end(&mut streamed)
+            .unwrap();
+        assert_eq!(streamed, data);
+    }
+}
@@ -10,9 +10,19 @@ pub enum WadError {
     #[error("invalid version {major:?}.{minor:?}")]
     InvalidVersion { major: u8, minor: u8 },
 
+    #[error("invalid entry count: {count}")]
+    InvalidEntryCount { count: i32 },
+
     #[error("invalid chunk compression: {compression:?}")]
     InvalidChunkCompression { compression: u8 },
 
+    #[error("failed to read entry {entry_index}")]
+    EntryParseError {
+        entry_index: usize,
+        #[source]
+        source: Box<WadError>,
+    },
+
     #[error("duplicate chunk: {path_hash:#08x}")]
     DuplicateChunk { path_hash: u64 },
 
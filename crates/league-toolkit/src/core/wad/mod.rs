@@ -1,8 +1,10 @@
 mod chunk;
+mod chunk_kind;
 mod decoder;
 mod error;
 
 pub use chunk::*;
+pub use chunk_kind::*;
 pub use decoder::*;
 pub use error::*;
 
@@ -27,7 +29,22 @@ impl<TSource: Read + Seek> Wad<TSource> {
     }
 
     pub fn mount(mut source: TSource) -> Result<Wad<TSource>, WadError> {
-        let mut reader = BufReader::new(&mut source);
+        let chunks = Self::read_chunks(&mut source)?;
+        Ok(Wad { chunks, source })
+    }
+
+    /// Re-reads the table of contents from the start of the source, replacing the current chunks.
+    ///
+    /// Useful when the underlying file was modified on disk. Chunks obtained before the reload
+    /// may point at stale offsets and should not be decoded afterwards.
+    pub fn reload(&mut self) -> Result<(), WadError> {
+        self.source.seek(SeekFrom::Start(0))?;
+        self.chunks = Self::read_chunks(&mut self.source)?;
+        Ok(())
+    }
+
+    fn read_chunks(source: &mut TSource) -> Result<HashMap<u64, WadChunk>, WadError> {
+        let mut reader = BufReader::new(source);
 
         // 0x5752 = "RW"
         let magic = reader.read_u16::<LE>()?;
@@ -58,10 +75,16 @@ impl<TSource: Read + Seek> Wad<TSource> {
             let _toc_chunk_size = reader.seek(SeekFrom::Current(2))?;
         }
 
-        let chunk_count = reader.read_i32::<LE>()? as usize;
-        let mut chunks = HashMap::<u64, WadChunk>::with_capacity(chunk_count);
-        for _ in 0..chunk_count {
-            let chunk = WadChunk::read(&mut reader)?;
+        let chunk_count = reader.read_i32::<LE>()?;
+        let chunk_count = usize::try_from(chunk_count)
+            .map_err(|_| WadError::InvalidEntryCount { count: chunk_count })?;
+        // the count is untrusted - don't let a corrupt header allocate gigabytes up front
+        let mut chunks = HashMap::<u64, WadChunk>::with_capacity(chunk_count.min(0x10000));
+        for entry_index in 0..chunk_count {
+            let chunk = WadChunk::read(&mut reader).map_err(|e| WadError::EntryParseError {
+                entry_index,
+                source: Box::new(e),
+            })?;
             chunks
                 .insert(chunk.path_hash(), chunk)
                 .map_or(Ok(()), |chunk| {
@@ -71,7 +94,7 @@ impl<TSource: Read + Seek> Wad<TSource> {
                 })?;
         }
 
-        Ok(Wad { chunks, source })
+        Ok(chunks)
     }
 
     pub fn decode(&mut self) -> (WadDecoder<'_, TSource>, &HashMap<u64, WadChunk>) {
@@ -83,3 +106,80 @@ impl<TSource: Read + Seek> Wad<TSource> {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use byteorder::WriteBytesExt as _;
+
+    fn wad_bytes(path_hashes: &[u64]) -> Vec<u8> {
+        let mut data = b"RW".to_vec();
+        data.extend([3, 4]);
+        data.extend([0; 256 + 8]);
+        data.write_i32::<LE>(path_hashes.len() as i32).unwrap();
+        for &path_hash in path_hashes {
+            data.write_u64::<LE>(path_hash).unwrap();
+            data.extend([0; 24]);
+        }
+        data
+    }
+
+    #[test]
+    fn reload() {
+        let path = std::env::temp_dir().join(format!("ltk-wad-reload-{}.wad", std::process::id()));
+        std::fs::write(&path, wad_bytes(&[1, 2])).unwrap();
+
+        let mut wad = Wad::mount(std::fs::File::open(&path).unwrap()).unwrap();
+        assert_eq!(wad.chunks().len(), 2);
+
+        std::fs::write(&path, wad_bytes(&[1, 2, 3])).unwrap();
+        let result = wad.reload();
+        std::fs::remove_file(&path).unwrap();
+
+        result.unwrap();
+        assert_eq!(wad.chunks().len(), 3);
+        assert!(wad.chunks().contains_key(&3));
+    }
+
+    #[test]
+    fn invalid_entry() {
+        let mut data = wad_bytes(&[1, 2]);
+        // compression type of the second entry
+        data[272 + 32 + 20] = 0xF;
+
+        let err = Wad::mount(std::io::Cursor::new(data)).unwrap_err();
+        let WadError::EntryParseError {
+            entry_index,
+            source,
+        } = err
+        else {
+            panic!("unexpected error: {err:?}");
+        };
+        assert_eq!(entry_index, 1);
+        assert!(matches!(
+            *source,
+            WadError::InvalidChunkCompression { compression: 0xF }
+        ));
+    }
+
+    #[test]
+    fn invalid_entry_count() {
+        let mut data = wad_bytes(&[]);
+        data[268..272].copy_from_slice(&(-1_i32).to_le_bytes());
+
+        let err = Wad::mount(std::io::Cursor::new(data)).unwrap_err();
+        assert!(matches!(err, WadError::InvalidEntryCount { count: -1 }));
+    }
+
+    #[test]
+    fn truncated_entries() {
+        let mut data = wad_bytes(&[1]);
+        data[268..272].copy_from_slice(&i32::MAX.to_le_bytes());
+
+        let err = Wad::mount(std::io::Cursor::new(data)).unwrap_err();
+        assert!(matches!(
+            err,
+            WadError::EntryParseError { entry_index: 1, .. }
+        ));
+    }
+}
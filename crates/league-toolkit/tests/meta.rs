@@ -25,3 +25,56 @@ pub fn round_trip() {
 
     assert_eq!(a, b);
 }
+
+#[test]
+pub fn property_kind_histogram() {
+    let mut r = Cursor::new(include_bytes!("bins/leona_small.bin"));
+    let bin = BinTree::from_reader(&mut r).unwrap();
+
+    let histogram = bin
+        .property_kind_histogram()
+        .into_iter()
+        .collect::<std::collections::BTreeMap<_, _>>();
+    insta::assert_debug_snapshot!(histogram);
+}
+
+#[test]
+pub fn rename_all_hashes() {
+    let mut r = Cursor::new(include_bytes!("bins/leona_small.bin"));
+    let mut bin = BinTree::from_reader(&mut r).unwrap();
+    let original =
+        BinTree::from_reader(&mut Cursor::new(include_bytes!("bins/leona_small.bin"))).unwrap();
+
+    let (&path_hash, object) = bin.objects.iter().next().unwrap();
+    let name_hash = *object.properties.keys().next().unwrap();
+    let table = std::collections::HashMap::from([(path_hash, 1), (name_hash, 2)]);
+    let inverse = table.iter().map(|(&k, &v)| (v, k)).collect();
+
+    bin.rename_all_hashes(&table);
+    assert!(bin.objects.get(&1).unwrap().properties.contains_key(&2));
+    assert!(!bin.objects.contains_key(&path_hash));
+
+    bin.rename_all_hashes(&inverse);
+    assert_eq!(bin, original);
+}
+
+#[test]
+pub fn from_file() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/bins/leona_small.bin");
+    let bin = BinTree::from_file(path).unwrap();
+    assert!(!bin.objects.is_empty());
+
+    let err = BinTree::from_file("does/not/exist.bin").unwrap_err();
+    assert_eq!(err.to_string(), "Failed to read 'does/not/exist.bin'");
+}
+
+#[test]
+pub fn hash() {
+    let read =
+        || BinTree::from_reader(&mut Cursor::new(include_bytes!("bins/leona_small.bin"))).unwrap();
+    let mut modified = read();
+    modified.dependencies.push("data/shared.bin".to_string());
+
+    let trees = std::collections::HashSet::from([read(), read(), modified]);
+    assert_eq!(trees.len(), 2);
+}
@@ -0,0 +1,14 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ModProjectError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("TOML error: {0}")]
+    TomlSerialize(#[from] toml::ser::Error),
+
+    #[error("Unsupported mod project file extension: {0:?}")]
+    UnsupportedExtension(Option<String>),
+}
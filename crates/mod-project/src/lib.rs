@@ -1,4 +1,9 @@
-use serde::{Deserialize, Serialize};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
+use std::path::Path;
+
+pub use error::*;
+
+mod error;
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, PartialOrd)]
 pub struct ModProject {
@@ -6,7 +11,70 @@ pub struct ModProject {
     pub display_name: String,
     pub version: String,
     pub description: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
     pub authors: Vec<ModProjectAuthor>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_url"
+    )]
+    pub repository: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_url"
+    )]
+    pub homepage: Option<String>,
+}
+
+/// Deserializes an optional string, rejecting anything that isn't an absolute URL
+fn deserialize_url<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    let value = Option::<String>::deserialize(deserializer)?;
+    if let Some(value) = &value {
+        url::Url::parse(value)
+            .map_err(|e| D::Error::custom(format!("invalid url '{value}': {e}")))?;
+    }
+    Ok(value)
+}
+
+impl ModProject {
+    /// Whether the project has the given keyword, ignoring case
+    pub fn has_keyword(&self, keyword: &str) -> bool {
+        self.keywords
+            .iter()
+            .any(|k| k.eq_ignore_ascii_case(keyword))
+    }
+
+    pub fn to_json_string(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    pub fn to_toml_string(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string(self)
+    }
+
+    /// Saves the project to `path`, picking JSON or TOML based on the file extension.
+    ///
+    /// The file is written to a sibling `.tmp` file first and then renamed over `path`.
+    pub fn to_file(&self, path: &Path) -> Result<(), ModProjectError> {
+        let extension = path.extension().and_then(|e| e.to_str());
+        let content = match extension {
+            Some(e) if e.eq_ignore_ascii_case("json") => self.to_json_string()?,
+            Some(e) if e.eq_ignore_ascii_case("toml") => self.to_toml_string()?,
+            _ => {
+                return Err(ModProjectError::UnsupportedExtension(
+                    extension.map(str::to_string),
+                ))
+            }
+        };
+
+        let mut tmp_path = path.as_os_str().to_owned();
+        tmp_path.push(".tmp");
+        std::fs::write(&tmp_path, content)?;
+        std::fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, PartialOrd)]
@@ -32,6 +100,7 @@ mod tests {
                 display_name: "Test 123".to_string(),
                 version: "0.1.0".to_string(),
                 description: "test".to_string(),
+                keywords: vec!["skin".to_string(), "Annie".to_string()],
                 authors: vec![
                     ModProjectAuthor::Name("test".to_string()),
                     ModProjectAuthor::Role {
@@ -39,7 +108,48 @@ mod tests {
                         role: "developer".to_string(),
                     },
                 ],
+                repository: Some("https://github.com/LeagueToolkit/league-toolkit".to_string()),
+                homepage: Some("https://example.com/mods/test".to_string()),
             }
         );
+        assert!(project.has_keyword("annie"));
+        assert!(!project.has_keyword("leona"));
+    }
+
+    #[test]
+    fn relative_url_is_rejected() {
+        let result = toml::from_str::<ModProject>(
+            &include_str!("../test-data/modproject.toml")
+                .replace("https://example.com/mods/test", "mods/test"),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn to_file_round_trip() {
+        let project: ModProject =
+            toml::from_str(include_str!("../test-data/modproject.toml")).unwrap();
+        let dir = std::env::temp_dir().join(format!("mod-project-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let toml_path = dir.join("modproject.toml");
+        project.to_file(&toml_path).unwrap();
+        let from_toml: ModProject =
+            toml::from_str(&std::fs::read_to_string(&toml_path).unwrap()).unwrap();
+
+        let json_path = dir.join("modproject.json");
+        project.to_file(&json_path).unwrap();
+        let from_json: ModProject =
+            serde_json::from_str(&std::fs::read_to_string(&json_path).unwrap()).unwrap();
+
+        assert!(matches!(
+            project.to_file(&dir.join("modproject.yaml")),
+            Err(ModProjectError::UnsupportedExtension(Some(_)))
+        ));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(from_toml, project);
+        assert_eq!(from_json, project);
     }
 }